@@ -0,0 +1,53 @@
+use aoc_rust_2023::bench::{self, BenchConfig};
+use aoc_rust_2023::solvers::SOLVERS;
+use aoc_rust_2023::timeout::CancellationToken;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() {
+    let config = BenchConfig::default();
+
+    for solver in SOLVERS {
+        let input_path: PathBuf = [
+            env!("CARGO_MANIFEST_DIR"),
+            "src",
+            "input",
+            solver.input_file,
+        ]
+        .iter()
+        .collect();
+
+        let puzzle_input = match fs::read_to_string(&input_path) {
+            Ok(puzzle_input) => puzzle_input,
+            Err(_) => continue,
+        };
+
+        // Parse once, like the solvers of a day share their model, and time only the solve.
+        let model = match (solver.parse)(&puzzle_input) {
+            Ok(model) => model,
+            Err(error) => {
+                eprintln!("Day {}, Part {}: {}", solver.day, solver.part, error);
+                continue;
+            }
+        };
+        let token = CancellationToken::new();
+
+        if let Err(error) = (solver.solve)(&model, &token) {
+            eprintln!("Day {}, Part {}: {}", solver.day, solver.part, error);
+            continue;
+        }
+
+        let statistics = bench::run(&config, || (solver.solve)(&model, &token));
+
+        println!(
+            "Day {}, Part {} ({}): median {}, mean {}, p95 {} over {} iterations",
+            solver.day,
+            solver.part,
+            solver.title,
+            bench::format_duration(statistics.median),
+            bench::format_duration(statistics.mean),
+            bench::format_duration(statistics.p95),
+            statistics.iterations
+        );
+    }
+}
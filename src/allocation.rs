@@ -0,0 +1,158 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Global allocator forwarding to the system allocator, counting allocations once enabled.
+// Counters are kept per thread, so solvers running in parallel are measured separately.
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy)]
+struct Counters {
+    allocations: usize,
+    bytes_allocated: usize,
+    live_bytes: usize,
+    peak_live_bytes: usize,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            allocations: 0,
+            bytes_allocated: 0,
+            live_bytes: 0,
+            peak_live_bytes: 0,
+        })
+    };
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AllocationStats {
+    pub allocations: usize,
+    pub bytes_allocated: usize,
+    // Highest number of bytes allocated at once during the measurement, on top of what was
+    // already allocated when it started.
+    pub peak_live_bytes: usize,
+}
+
+// The counters are gone while the thread is being torn down, its allocations are then ignored.
+fn update_counters(update: impl FnOnce(&mut Counters)) {
+    let _ = COUNTERS.try_with(|cell| {
+        let mut counters = cell.get();
+        update(&mut counters);
+        cell.set(counters);
+    });
+}
+
+fn record_allocation(size: usize) {
+    update_counters(|counters| {
+        counters.allocations += 1;
+        counters.bytes_allocated += size;
+        counters.live_bytes += size;
+        counters.peak_live_bytes = counters.peak_live_bytes.max(counters.live_bytes);
+    });
+}
+
+fn record_deallocation(size: usize) {
+    // Memory allocated before counting was enabled, or by another thread, may be freed here.
+    update_counters(|counters| counters.live_bytes = counters.live_bytes.saturating_sub(size));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_allocation(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_allocation(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        if ENABLED.load(Ordering::Relaxed) {
+            record_deallocation(layout.size());
+        }
+    }
+
+    // A reallocation counts as a new allocation of the new size replacing the old one.
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_deallocation(layout.size());
+            record_allocation(new_size);
+        }
+        new_pointer
+    }
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+// Run `function` and return the allocations it made on the current thread. All counters stay
+// at zero when counting is disabled or when CountingAllocator isn't the global allocator.
+pub fn measure<T, F: FnOnce() -> T>(function: F) -> (T, AllocationStats) {
+    let start = COUNTERS.with(|cell| {
+        let mut counters = cell.get();
+        counters.peak_live_bytes = counters.live_bytes;
+        cell.set(counters);
+        counters
+    });
+
+    let result = function();
+
+    let end = COUNTERS.with(Cell::get);
+    let stats = AllocationStats {
+        allocations: end.allocations - start.allocations,
+        bytes_allocated: end.bytes_allocated - start.bytes_allocated,
+        peak_live_bytes: end.peak_live_bytes.saturating_sub(start.live_bytes),
+    };
+
+    (result, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::alloc::{GlobalAlloc, Layout};
+
+    use crate::allocation::{enable, is_enabled, measure, AllocationStats, CountingAllocator};
+
+    #[test]
+    fn test_measure() {
+        // The test binary uses the system allocator, so allocations are made explicitly.
+        enable();
+        assert!(is_enabled());
+
+        let ((), stats) = measure(|| unsafe {
+            let layout = Layout::from_size_align(64, 8).unwrap();
+            let first = CountingAllocator.alloc(layout);
+            let second = CountingAllocator.alloc_zeroed(layout);
+            CountingAllocator.dealloc(first, layout);
+
+            let third = CountingAllocator.realloc(second, layout, 256);
+            CountingAllocator.dealloc(third, Layout::from_size_align(256, 8).unwrap());
+        });
+
+        assert_eq!(
+            stats,
+            AllocationStats {
+                allocations: 3,
+                bytes_allocated: 384,
+                peak_live_bytes: 256,
+            }
+        );
+    }
+}
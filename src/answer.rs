@@ -0,0 +1,171 @@
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+// Solution of a puzzle part. Numbers are compared by value whatever their signedness.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+}
+
+macro_rules! answer_from_signed {
+    ($($integer:ty),*) => {
+        $(impl From<$integer> for Answer {
+            fn from(value: $integer) -> Self {
+                Answer::Signed(value as i128)
+            }
+        })*
+    };
+}
+
+macro_rules! answer_from_unsigned {
+    ($($integer:ty),*) => {
+        $(impl From<$integer> for Answer {
+            fn from(value: $integer) -> Self {
+                Answer::Unsigned(value as u128)
+            }
+        })*
+    };
+}
+
+answer_from_signed!(i8, i16, i32, i64, i128, isize);
+answer_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+// Integers are parsed as numbers, anything else is kept as text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+
+        if let Ok(unsigned) = value.parse::<u128>() {
+            return Ok(Answer::Unsigned(unsigned));
+        }
+
+        if let Ok(signed) = value.parse::<i128>() {
+            return Ok(Answer::Signed(signed));
+        }
+
+        Ok(Answer::Text(value.to_string()))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Answer {
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::Signed(value) => Some(*value),
+            Answer::Unsigned(value) => i128::try_from(*value).ok(),
+            Answer::Text(_) => None,
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        !matches!(self, Answer::Text(_))
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+// Numbers are ordered by value and text lexicographically; a number and a text are not comparable.
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Signed(left), Answer::Signed(right)) => left.partial_cmp(right),
+            (Answer::Unsigned(left), Answer::Unsigned(right)) => left.partial_cmp(right),
+            (Answer::Signed(left), Answer::Unsigned(right)) => match u128::try_from(*left) {
+                Ok(left) => left.partial_cmp(right),
+                Err(_) => Some(Ordering::Less),
+            },
+            (Answer::Unsigned(_), Answer::Signed(_)) => {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+            (Answer::Text(left), Answer::Text(right)) => left.partial_cmp(right),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_from_integers() {
+        assert_eq!(Answer::from(-5_i64).to_string(), "-5");
+        assert_eq!(Answer::from(42_usize).to_string(), "42");
+        assert_eq!(
+            Answer::from(u128::MAX).to_string(),
+            "340282366920938463463374607431768211455"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(matches!("4361".parse(), Ok(Answer::Unsigned(4361))));
+        assert!(matches!("-12".parse(), Ok(Answer::Signed(-12))));
+        assert!(matches!(" 7 ".parse(), Ok(Answer::Unsigned(7))));
+        assert!(
+            matches!("EFEBJRCK".parse::<Answer>(), Ok(Answer::Text(text)) if text == "EFEBJRCK")
+        );
+    }
+
+    #[test]
+    fn test_numeric_equality() {
+        assert_eq!(Answer::from(142_i64), Answer::from(142_u32));
+        assert_eq!(Answer::from(142_u64), "142".parse::<Answer>().unwrap());
+        assert_ne!(Answer::from(-1_i64), Answer::from(u128::MAX));
+        assert_ne!(Answer::from(142_u64), Answer::from("142"));
+        assert_eq!(Answer::from("abc"), Answer::from("abc".to_string()));
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Answer::from(-1_i64) < Answer::from(0_u64));
+        assert!(Answer::from(5_u64) > Answer::from(4_i32));
+        assert!(Answer::from(i128::MAX) < Answer::from(u128::MAX));
+        assert_eq!(
+            Answer::from(1_u8).partial_cmp(&Answer::from("1")),
+            None::<Ordering>
+        );
+    }
+
+    #[test]
+    fn test_as_i128() {
+        assert_eq!(Answer::from(7_u64).as_i128(), Some(7));
+        assert_eq!(Answer::from(u128::MAX).as_i128(), None);
+        assert_eq!(Answer::from("x").as_i128(), None);
+        assert!(Answer::from(-3_i64).is_numeric());
+        assert!(!Answer::from("x").is_numeric());
+    }
+}
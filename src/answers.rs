@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::answer::Answer;
+use crate::line_file;
+
+// Expected answers, loaded from a file with one "<day> <part> <answer>" entry per line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    entries: BTreeMap<(u8, u8), Answer>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Missing,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => f.pad("PASS"),
+            Verdict::Fail { .. } => f.pad("FAIL"),
+            Verdict::Missing => f.pad("MISSING"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut entries: BTreeMap<(u8, u8), Answer> = BTreeMap::new();
+
+        line_file::parse_lines(content, |line| {
+            let mut fields = line.splitn(3, char::is_whitespace);
+
+            let (day, part, answer) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(day), Some(part), Some(answer)) => (day, part, answer.trim()),
+                _ => {
+                    return Err(format!(
+                        "expected '<day> <part> <answer>', found {:?}",
+                        line
+                    ))
+                }
+            };
+
+            let day = match day.parse::<u8>() {
+                Ok(day) => day,
+                Err(_) => return Err(format!("invalid day {:?}", day)),
+            };
+            let part = match part.parse::<u8>() {
+                Ok(part) => part,
+                Err(_) => return Err(format!("invalid part {:?}", part)),
+            };
+
+            let Ok(answer) = answer.parse::<Answer>();
+
+            match entries.insert((day, part), answer) {
+                Some(_) => Err(format!(
+                    "duplicate answer for day {} and part {}",
+                    day, part
+                )),
+                None => Ok(()),
+            }
+        })?;
+
+        Ok(Self { entries })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.entries.get(&(day, part))
+    }
+
+    pub fn verify<E>(&self, day: u8, part: u8, result: &Result<Answer, E>) -> Verdict {
+        match (self.get(day, part), result) {
+            (None, _) => Verdict::Missing,
+            (Some(expected), Ok(solution)) if expected == solution => Verdict::Pass,
+            (Some(expected), _) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::answers::{Answers, Verdict};
+    use crate::error::SolveError;
+
+    static TEST_ANSWERS: &str = "# Day 1
+1 1 142
+1 2   281
+
+3 2 467835
+";
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(TEST_ANSWERS).unwrap();
+        assert_eq!(answers.get(1, 1), Some(&Answer::from(142)));
+        assert_eq!(answers.get(1, 2), Some(&Answer::from(281)));
+        assert_eq!(answers.get(3, 2), Some(&Answer::from(467835)));
+        assert_eq!(answers.get(3, 1), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_lines() {
+        assert_eq!(
+            Answers::parse("1 1"),
+            Err("Line 1: expected '<day> <part> <answer>', found \"1 1\"".to_string())
+        );
+        assert_eq!(
+            Answers::parse("1 1 142\nx 1 142"),
+            Err("Line 2: invalid day \"x\"".to_string())
+        );
+        assert_eq!(
+            Answers::parse("1 one 142"),
+            Err("Line 1: invalid part \"one\"".to_string())
+        );
+        assert_eq!(
+            Answers::parse("1 1 142\n1 1 143"),
+            Err("Line 2: duplicate answer for day 1 and part 1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse(TEST_ANSWERS).unwrap();
+        assert_eq!(
+            answers.verify::<SolveError>(1, 1, &Ok(Answer::from(142_u32))),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.verify::<SolveError>(1, 2, &Ok(Answer::from(280_i64))),
+            Verdict::Fail {
+                expected: Answer::from(281)
+            }
+        );
+        assert_eq!(
+            answers.verify(
+                3,
+                2,
+                &Err(SolveError::invalid_input("Input has no new line character"))
+            ),
+            Verdict::Fail {
+                expected: Answer::from(467835)
+            }
+        );
+        assert_eq!(
+            answers.verify::<SolveError>(4, 1, &Ok(Answer::from(13))),
+            Verdict::Missing
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::line_file;
+
+// Median benchmark time of each solver, stored with one "<day> <part> <median ns>" entry per line.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    entries: BTreeMap<(u8, u8), Duration>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Comparison {
+    Within { baseline: Duration },
+    Slower { baseline: Duration },
+    Missing,
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Comparison::Within { .. } => f.pad("OK"),
+            Comparison::Slower { .. } => f.pad("SLOWER"),
+            Comparison::Missing => f.pad("MISSING"),
+        }
+    }
+}
+
+// Relative change from `baseline` to `median`, e.g. 25.0 for a solver 25% slower.
+pub fn change_percent(baseline: Duration, median: Duration) -> f64 {
+    (median.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0
+}
+
+impl Baseline {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut entries = BTreeMap::new();
+
+        line_file::parse_lines(content, |line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+
+            let entry = match fields[..] {
+                [day, part, median] => {
+                    match (day.parse::<u8>(), part.parse::<u8>(), median.parse::<u64>()) {
+                        (Ok(day), Ok(part), Ok(median)) => Some((day, part, median)),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+
+            match entry {
+                Some((day, part, median)) => {
+                    entries.insert((day, part), Duration::from_nanos(median));
+                    Ok(())
+                }
+                None => Err(format!(
+                    "expected '<day> <part> <median ns>', found {:?}",
+                    line
+                )),
+            }
+        })?;
+
+        Ok(Self { entries })
+    }
+
+    // A missing baseline file is an empty baseline.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        Self::parse(&line_file::read(path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<Duration> {
+        self.entries.get(&(day, part)).copied()
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, median: Duration) {
+        self.entries.insert((day, part), median);
+    }
+
+    // Compare a new median time to the baseline, allowing it to be slower by `max_regression`
+    // percent.
+    pub fn compare(&self, day: u8, part: u8, median: Duration, max_regression: f64) -> Comparison {
+        match self.get(day, part) {
+            Some(baseline) if change_percent(baseline, median) > max_regression => {
+                Comparison::Slower { baseline }
+            }
+            Some(baseline) => Comparison::Within { baseline },
+            None => Comparison::Missing,
+        }
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# <day> <part> <median ns>")?;
+
+        for ((day, part), median) in &self.entries {
+            writeln!(f, "{} {} {}", day, part, median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::Duration;
+
+    use crate::baseline::{change_percent, Baseline, Comparison};
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn test_parse() {
+        let baseline = Baseline::parse("# Baseline\n4 2 3000000\n\n3 2 800000\n").unwrap();
+
+        assert_eq!(baseline.get(4, 2), Some(Duration::from_millis(3)));
+        assert_eq!(baseline.get(3, 2), Some(Duration::from_micros(800)));
+        assert_eq!(baseline.get(3, 1), None);
+        assert_eq!(
+            Baseline::parse("4 2 3ms"),
+            Err("Line 1: expected '<day> <part> <median ns>', found \"4 2 3ms\"".to_string())
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Baseline::parse("4 2 3000000").unwrap();
+
+        assert_eq!(
+            baseline.compare(4, 2, Duration::from_micros(3_200), 10.0),
+            Comparison::Within {
+                baseline: Duration::from_millis(3)
+            }
+        );
+        assert_eq!(
+            baseline.compare(4, 2, Duration::from_micros(3_600), 10.0),
+            Comparison::Slower {
+                baseline: Duration::from_millis(3)
+            }
+        );
+        assert_eq!(
+            baseline.compare(4, 1, Duration::from_micros(10), 10.0),
+            Comparison::Missing
+        );
+    }
+
+    #[test]
+    fn test_change_percent() {
+        let slower = change_percent(Duration::from_micros(200), Duration::from_micros(250));
+        let faster = change_percent(Duration::from_micros(200), Duration::from_micros(150));
+
+        assert!((slower - 25.0).abs() < 1e-9);
+        assert!((faster + 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_save() {
+        let temp_dir = TempDir::new("baseline");
+        let path = temp_dir.join("baseline.txt");
+        let mut baseline = Baseline::default();
+        baseline.insert(4, 2, Duration::from_millis(3));
+        baseline.insert(1, 1, Duration::from_micros(900));
+
+        baseline.save(&path).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# <day> <part> <median ns>\n1 1 900000\n4 2 3000000\n"
+        );
+        assert_eq!(Baseline::load(&path), Ok(baseline));
+    }
+}
@@ -0,0 +1,182 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub struct BenchConfig {
+    pub warmup_iterations: u32,
+    pub min_iterations: u32,
+    pub max_iterations: u32,
+    // Timed iterations keep going until this budget is spent (or max_iterations is reached).
+    pub time_budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup_iterations: 3,
+            min_iterations: 10,
+            max_iterations: 10_000,
+            time_budget: Duration::from_millis(500),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Statistics {
+    pub iterations: usize,
+    pub min: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Statistics {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted_samples = samples.to_vec();
+        sorted_samples.sort();
+
+        let iterations = sorted_samples.len();
+        let mean_secs = sorted_samples
+            .iter()
+            .map(Duration::as_secs_f64)
+            .sum::<f64>()
+            / iterations as f64;
+        let variance = sorted_samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>()
+            / iterations as f64;
+
+        let median = if iterations.is_multiple_of(2) {
+            (sorted_samples[iterations / 2 - 1] + sorted_samples[iterations / 2]) / 2
+        } else {
+            sorted_samples[iterations / 2]
+        };
+
+        // Nearest-rank percentile.
+        let p95_rank = (iterations as f64 * 0.95).ceil() as usize;
+
+        Some(Self {
+            iterations,
+            min: sorted_samples[0],
+            mean: Duration::from_secs_f64(mean_secs),
+            median,
+            p95: sorted_samples[p95_rank.max(1) - 1],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+pub fn run<T, F: FnMut() -> T>(config: &BenchConfig, mut function: F) -> Statistics {
+    for _ in 0..config.warmup_iterations {
+        black_box(function());
+    }
+
+    // At least one timed iteration, so there are always statistics.
+    let min_iterations = config.min_iterations.max(1) as usize;
+    let mut samples: Vec<Duration> = Vec::new();
+    let bench_start = Instant::now();
+
+    while samples.len() < min_iterations
+        || (samples.len() < config.max_iterations as usize
+            && bench_start.elapsed() < config.time_budget)
+    {
+        let chrono_start = Instant::now();
+        black_box(function());
+        samples.push(chrono_start.elapsed());
+    }
+
+    match Statistics::from_samples(&samples) {
+        Some(statistics) => statistics,
+        None => unreachable!(),
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}μs", nanos as f64 / 1_000.0)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1_000_000.0)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::{format_duration, run, BenchConfig, Statistics};
+
+    #[test]
+    fn test_statistics() {
+        let samples: Vec<Duration> = [5, 1, 4, 2, 3].map(Duration::from_micros).to_vec();
+
+        assert_eq!(
+            Statistics::from_samples(&samples),
+            Some(Statistics {
+                iterations: 5,
+                min: Duration::from_micros(1),
+                mean: Duration::from_micros(3),
+                median: Duration::from_micros(3),
+                p95: Duration::from_micros(5),
+                stddev: Duration::from_secs_f64(2.0_f64.sqrt() / 1_000_000.0),
+            })
+        );
+        assert_eq!(Statistics::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_statistics_even_number_of_samples() {
+        let samples: Vec<Duration> = (1..=20).map(Duration::from_micros).collect();
+        let statistics = Statistics::from_samples(&samples).unwrap();
+
+        assert_eq!(statistics.median, Duration::from_nanos(10_500));
+        assert_eq!(statistics.p95, Duration::from_micros(19));
+    }
+
+    #[test]
+    fn test_run_iterations() {
+        let mut calls = 0;
+        let config = BenchConfig {
+            warmup_iterations: 2,
+            min_iterations: 5,
+            max_iterations: 8,
+            time_budget: Duration::from_secs(60),
+        };
+
+        assert_eq!(run(&config, || calls += 1).iterations, 8);
+        assert_eq!(calls, 10);
+
+        let config = BenchConfig {
+            time_budget: Duration::ZERO,
+            ..config
+        };
+
+        assert_eq!(run(&config, || ()).iterations, 5);
+
+        let config = BenchConfig {
+            min_iterations: 0,
+            max_iterations: 0,
+            ..config
+        };
+
+        assert_eq!(run(&config, || ()).iterations, 1);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3μs");
+        assert_eq!(format_duration(Duration::from_micros(4_567)), "4.57ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50s");
+    }
+}
@@ -0,0 +1,346 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::answer::Answer;
+use crate::submission::Outcome;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+const YEAR: u16 = 2023;
+const USER_AGENT: &str = "github.com/davidlag0/advent-of-code-2023";
+
+#[derive(Debug, PartialEq)]
+pub enum ClientError {
+    MissingSession,
+    Unauthorized,
+    NotFound(String),
+    Status(u16),
+    Transport(String),
+    Io(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "No session token found, set the {} environment variable or write it to ~/{}",
+                SESSION_VARIABLE, SESSION_FILE
+            ),
+            ClientError::Unauthorized => write!(
+                f,
+                "The session token was rejected, it may be invalid or expired"
+            ),
+            ClientError::NotFound(url) => write!(
+                f,
+                "Nothing found at {}, the puzzle may not be unlocked yet",
+                url
+            ),
+            ClientError::Status(status) => write!(f, "Unexpected HTTP status {}", status),
+            ClientError::Transport(message) => write!(f, "Request failed: {}", message),
+            ClientError::Io(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+// Outcome of fetching a puzzle input, with the path of the input file.
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+// Session token from the environment, or else from `session_file` (~/.adventofcode.session by default).
+pub fn session_token(session_file: Option<&Path>) -> Option<String> {
+    let from_environment = env::var(SESSION_VARIABLE).ok();
+    let session_file = match session_file {
+        Some(session_file) => Some(session_file.to_path_buf()),
+        None => env::var_os("HOME").map(|home| Path::new(&home).join(SESSION_FILE)),
+    };
+
+    session_token_from(from_environment, session_file.as_deref())
+}
+
+fn session_token_from(
+    from_environment: Option<String>,
+    session_file: Option<&Path>,
+) -> Option<String> {
+    let token = match from_environment {
+        Some(token) if !token.trim().is_empty() => token,
+        _ => fs::read_to_string(session_file?).ok()?,
+    };
+    let token = token.trim();
+    let token = token.strip_prefix("session=").unwrap_or(token);
+
+    match token.is_empty() {
+        true => None,
+        false => Some(token.to_string()),
+    }
+}
+
+pub fn input_file(input_dir: &Path, day: u8) -> PathBuf {
+    input_dir.join(format!("day{}.txt", day))
+}
+
+// Client for the Advent of Code website, or a stand-in served at another base URL.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    fn cookie(&self) -> Result<String, ClientError> {
+        match &self.session {
+            Some(session) => Ok(format!("session={}", session)),
+            None => Err(ClientError::MissingSession),
+        }
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    pub fn download_input(&self, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/input", self.day_url(day));
+
+        match self.agent.get(&url).set("Cookie", &self.cookie()?).call() {
+            Ok(response) => match response.into_string() {
+                Ok(puzzle_input) => Ok(puzzle_input),
+                Err(error) => Err(ClientError::Transport(error.to_string())),
+            },
+            Err(error) => Err(request_error(error, url)),
+        }
+    }
+
+    // Download the input of `day` into `input_dir`, unless it's already there.
+    pub fn fetch_input(&self, input_dir: &Path, day: u8) -> Result<Fetched, ClientError> {
+        let path = input_file(input_dir, day);
+
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let puzzle_input = self.download_input(day)?;
+
+        // Written next to the input file first, so an interrupted write is never taken for a cached input.
+        let partial_path = path.with_extension("txt.part");
+        match fs::create_dir_all(input_dir)
+            .and_then(|_| fs::write(&partial_path, puzzle_input))
+            .and_then(|_| fs::rename(&partial_path, &path))
+        {
+            Ok(()) => Ok(Fetched::Downloaded(path)),
+            Err(error) => Err(ClientError::Io(format!(
+                "Could not write '{}'. {}",
+                path.display(),
+                error
+            ))),
+        }
+    }
+
+    pub fn submit_answer(
+        &self,
+        day: u8,
+        part: u8,
+        answer: &Answer,
+    ) -> Result<Outcome, ClientError> {
+        let url = format!("{}/answer", self.day_url(day));
+
+        match self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie()?)
+            .send_form(&[
+                ("level", &part.to_string()),
+                ("answer", &answer.to_string()),
+            ]) {
+            Ok(response) => match response.into_string() {
+                Ok(page) => Ok(Outcome::from_response(&page)),
+                Err(error) => Err(ClientError::Transport(error.to_string())),
+            },
+            Err(error) => Err(request_error(error, url)),
+        }
+    }
+}
+
+fn request_error(error: ureq::Error, url: String) -> ClientError {
+    match error {
+        ureq::Error::Status(404, _) => ClientError::NotFound(url),
+        ureq::Error::Status(400 | 401 | 403, _) => ClientError::Unauthorized,
+        ureq::Error::Status(status, _) => ClientError::Status(status),
+        ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    use crate::answer::Answer;
+    use crate::client::{session_token_from, Client, ClientError, Fetched};
+    use crate::submission::Outcome;
+    use crate::temp_dir::TempDir;
+
+    // Local stand-in for the website, answering a single request with `response`.
+    // The handle returns the request that was received.
+    fn serve_once(response: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8_lossy(&body));
+
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = serve_once(
+            "HTTP/1.1 200 OK\r\nContent-Length: 12\r\nConnection: close\r\n\r\n467..114..\n\n",
+        );
+        let temp_dir = TempDir::new("fetch");
+        let input_dir = temp_dir.join("input");
+        let client = Client::new(&format!("{}/", base_url), Some("53cr3t".to_string()));
+
+        assert_eq!(
+            client.fetch_input(&input_dir, 3),
+            Ok(Fetched::Downloaded(input_dir.join("day3.txt")))
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/3/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=53cr3t\r\n"));
+        assert_eq!(
+            fs::read_to_string(input_dir.join("day3.txt")).unwrap(),
+            "467..114..\n\n"
+        );
+
+        // The stand-in is gone, so the input must come from the cache.
+        assert_eq!(
+            client.fetch_input(&input_dir, 3),
+            Ok(Fetched::Cached(input_dir.join("day3.txt")))
+        );
+    }
+
+    #[test]
+    fn test_fetch_input_not_unlocked() {
+        let (base_url, server) =
+            serve_once("HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+        let temp_dir = TempDir::new("not-unlocked");
+        let input_dir = temp_dir.join("input");
+        let client = Client::new(&base_url, Some("53cr3t".to_string()));
+
+        assert_eq!(
+            client.fetch_input(&input_dir, 25),
+            Err(ClientError::NotFound(format!(
+                "{}/2023/day/25/input",
+                base_url
+            )))
+        );
+        server.join().unwrap();
+        assert!(!input_dir.exists());
+    }
+
+    #[test]
+    fn test_fetch_input_unauthorized() {
+        let (base_url, server) = serve_once(
+            "HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        );
+        let client = Client::new(&base_url, Some("expired".to_string()));
+
+        assert_eq!(client.download_input(1), Err(ClientError::Unauthorized));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_without_session() {
+        let client = Client::new("http://127.0.0.1:1", None);
+
+        assert_eq!(
+            client.fetch_input(&TempDir::new("without-session").join("input"), 1),
+            Err(ClientError::MissingSession)
+        );
+    }
+
+    #[test]
+    fn test_session_token() {
+        let temp_dir = TempDir::new("session");
+        let session_file = temp_dir.join("session");
+        fs::write(&session_file, "session=f00d\n").unwrap();
+
+        assert_eq!(
+            session_token_from(Some("cafe".to_string()), Some(&session_file)),
+            Some("cafe".to_string())
+        );
+        assert_eq!(
+            session_token_from(Some(" ".to_string()), Some(&session_file)),
+            Some("f00d".to_string())
+        );
+        assert_eq!(
+            session_token_from(None, Some(&session_file)),
+            Some("f00d".to_string())
+        );
+        assert_eq!(session_token_from(None, None), None);
+
+        fs::remove_file(&session_file).unwrap();
+        assert_eq!(session_token_from(None, Some(&session_file)), None);
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, server) = serve_once(
+            "HTTP/1.1 200 OK\r\nConnection: close\r\n\r\n<article><p>That's not the right answer; your answer is too low.",
+        );
+        let client = Client::new(&base_url, Some("53cr3t".to_string()));
+
+        assert_eq!(
+            client.submit_answer(4, 2, &Answer::from(5539495)),
+            Ok(Outcome::TooLow)
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/4/answer HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=53cr3t\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=5539495"));
+    }
+}
@@ -45,6 +45,44 @@ What is the sum of all of the calibration values?
 
 use std::collections::BTreeMap;
 
+use crate::answer::Answer;
+use crate::error::SolveError;
+use crate::example::Example;
+use crate::solvers::FingerprintFn;
+use crate::validation::{all_lines_match, validate_lines, LineScanner};
+
+static EXAMPLE_INPUT_1: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+static EXAMPLE_INPUT_2: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+pub static EXAMPLES: &[Example] = &[
+    Example {
+        part: 1,
+        input: EXAMPLE_INPUT_1,
+        answer: "142",
+    },
+    Example {
+        part: 2,
+        input: EXAMPLE_INPUT_2,
+        answer: "281",
+    },
+];
+
+const ENGLISH_NUMBERS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
 fn calibration_value(raw_calibration: &str) -> u32 {
     let digits: Vec<char> = raw_calibration
         .chars()
@@ -64,21 +102,63 @@ fn calibration_value(raw_calibration: &str) -> u32 {
     0
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
+pub struct CalibrationDocument {
+    lines: Vec<String>,
+}
+
+pub fn parse(input: &str) -> Result<CalibrationDocument, SolveError> {
+    Ok(CalibrationDocument {
+        lines: input.split('\n').map(|line| line.to_string()).collect(),
+    })
+}
+
+fn validate_line(scanner: &mut LineScanner) -> Result<(), SolveError> {
+    while let Some(character) = scanner.peek() {
+        if !character.is_ascii_lowercase() && !character.is_ascii_digit() {
+            return Err(scanner.error(format!(
+                "Unexpected {:?}, expected a lowercase letter or a digit",
+                character
+            )));
+        }
+        scanner.next_char();
+    }
+
+    let line = scanner.line();
+    if !line.contains(|character: char| character.is_ascii_digit())
+        && !ENGLISH_NUMBERS.iter().any(|number| line.contains(number))
+    {
+        return Err(scanner.error_at(1, "No digit, written or spelled out, on the line"));
+    }
+
+    Ok(())
+}
+
+// Lines of lowercase letters and digits, each with at least one digit or spelled out digit.
+pub fn validate(input: &str) -> Vec<SolveError> {
+    validate_lines(input, validate_line)
+}
+
+// Free-form lines of letters and digits.
+pub fn fingerprint(input: &str) -> bool {
+    all_lines_match(input, |line| {
+        line.bytes().all(|byte| byte.is_ascii_alphanumeric())
+    })
+}
+
+pub static FINGERPRINT: Option<FingerprintFn> = Some(fingerprint);
+
+pub fn part1(document: &CalibrationDocument) -> Result<Answer, SolveError> {
     let mut sum_of_calibration_values = 0;
 
-    for line in input.split('\n') {
+    for line in &document.lines {
         sum_of_calibration_values += calibration_value(line);
     }
 
-    Ok(sum_of_calibration_values.to_string())
+    Ok(Answer::from(sum_of_calibration_values))
 }
 
 fn calibration_value_2(raw_calibration: &str) -> usize {
     let mut map: BTreeMap<usize, usize> = BTreeMap::new();
-    const ENGLISH_NUMBERS: [&str; 9] = [
-        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
-    ];
 
     let mut calibration_value: usize = 0;
 
@@ -113,8 +193,7 @@ fn calibration_value_2(raw_calibration: &str) -> usize {
     // Last digit
     match raw_calibration
         .char_indices()
-        .filter(|(_pos, char)| char.is_ascii_digit())
-        .last()
+        .rfind(|(_pos, char)| char.is_ascii_digit())
     {
         Some((position, character)) => match character.to_digit(10) {
             Some(digit) => map.insert(position, digit as usize),
@@ -134,52 +213,77 @@ fn calibration_value_2(raw_calibration: &str) -> usize {
     calibration_value
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
+pub fn part2(document: &CalibrationDocument) -> Result<Answer, SolveError> {
     let mut sum_of_calibration_values = 0;
 
-    for line in input.split('\n') {
+    for line in &document.lines {
         sum_of_calibration_values += calibration_value_2(line);
     }
 
-    Ok(sum_of_calibration_values.to_string())
+    Ok(Answer::from(sum_of_calibration_values))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day1::{part1, part2};
-
-    static TEST_INPUT: &str = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet
-abcdef";
-
-    static TEST_INPUT_2: &str = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen";
+    use crate::answer::Answer;
+    use crate::day1::{
+        fingerprint, parse, part1, part2, validate, EXAMPLE_INPUT_1, EXAMPLE_INPUT_2,
+    };
+    use crate::error::SolveError;
 
-    static TEST_INPUT_3: &str = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen
-abcdef
+    // Appended to the examples: a line without any digit and one with overlapping spelled digits.
+    static TEST_INPUT: &str = "abcdef
 jcb82eightwond";
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), Ok(142.to_string()));
+        assert_eq!(
+            part1(&parse(EXAMPLE_INPUT_1).unwrap()),
+            Ok(Answer::from(142))
+        );
+        assert_eq!(
+            part1(&parse(&format!("{}{}", EXAMPLE_INPUT_1, TEST_INPUT)).unwrap()),
+            Ok(Answer::from(224))
+        );
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(TEST_INPUT_2), Ok(281.to_string()));
-        assert_eq!(part2(TEST_INPUT_3), Ok(363.to_string()));
+        assert_eq!(
+            part2(&parse(EXAMPLE_INPUT_2).unwrap()),
+            Ok(Answer::from(281))
+        );
+        assert_eq!(
+            part2(&parse(&format!("{}{}", EXAMPLE_INPUT_2, TEST_INPUT)).unwrap()),
+            Ok(Answer::from(363))
+        );
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        assert!(fingerprint(EXAMPLE_INPUT_1));
+        assert!(fingerprint(EXAMPLE_INPUT_2));
+        assert!(fingerprint(TEST_INPUT));
+        assert!(!fingerprint("Game 1: 3 blue, 4 red\n"));
+        assert!(!fingerprint("467..114..\n...*......\n"));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate(EXAMPLE_INPUT_2), vec![]);
+        assert_eq!(
+            validate(&format!("{}{}", EXAMPLE_INPUT_1, TEST_INPUT)),
+            vec![
+                SolveError::invalid_input("No digit, written or spelled out, on the line").at(5, 1)
+            ]
+        );
+        assert_eq!(
+            validate("two1nine\n\nxtwo ne3\n"),
+            vec![
+                SolveError::invalid_input("Empty line").at(2, 1),
+                SolveError::invalid_input("Unexpected ' ', expected a lowercase letter or a digit")
+                    .at(3, 5),
+            ]
+        );
     }
 }
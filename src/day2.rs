@@ -52,6 +52,32 @@ The power of a set of cubes is equal to the numbers of red, green, and blue cube
 For each game, find the minimum set of cubes that must have been present. What is the sum of the power of these sets?
 */
 
+use crate::answer::Answer;
+use crate::error::SolveError;
+use crate::example::Example;
+use crate::solvers::FingerprintFn;
+use crate::validation::{all_lines_match, validate_lines, LineScanner};
+
+static EXAMPLE_INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+pub static EXAMPLES: &[Example] = &[
+    Example {
+        part: 1,
+        input: EXAMPLE_INPUT,
+        answer: "8",
+    },
+    Example {
+        part: 2,
+        input: EXAMPLE_INPUT,
+        answer: "2286",
+    },
+];
+
 const MAX_RED_CUBES: i64 = 12;
 const MAX_GREEN_CUBES: i64 = 13;
 const MAX_BLUE_CUBES: i64 = 14;
@@ -68,17 +94,17 @@ struct Cube {
 }
 
 impl Cube {
-    pub fn new(cube_string: &str) -> Result<Self, String> {
+    pub fn new(cube_string: &str) -> Result<Self, SolveError> {
         let cube_vec = cube_string.split_whitespace().collect::<Vec<&str>>();
 
         Ok(Self {
             count: match cube_vec[0].parse::<i64>() {
                 Ok(value) => value,
                 Err(_) => {
-                    return Err(format!(
+                    return Err(SolveError::parse(format!(
                         "Unable to parse cube string's number: {:?}",
                         cube_vec[0]
-                    ))
+                    )))
                 }
             },
             color: match cube_vec[1] {
@@ -86,41 +112,54 @@ impl Cube {
                 "green" => CubeColor::Green,
                 "blue" => CubeColor::Blue,
                 _ => {
-                    return Err(format!(
+                    return Err(SolveError::parse(format!(
                         "Unable to parse cube string's color: {:?}",
                         cube_vec[1]
-                    ))
+                    )))
                 }
             },
         })
     }
 }
 
-fn is_round_possible(game: &str) -> bool {
-    for cube in game
-        .split(',')
-        .map(|line| line.trim())
-        .collect::<Vec<&str>>()
-    {
-        let cube_vec = cube.split(' ').collect::<Vec<&str>>();
-        let cube_number = cube_vec[0].parse::<i64>().unwrap_or(0);
-
-        match cube_vec[1] {
-            "red" => {
-                if cube_number > MAX_RED_CUBES {
-                    return false;
-                }
-            }
-            "green" => {
-                if cube_number > MAX_GREEN_CUBES {
-                    return false;
-                }
-            }
-            "blue" => {
-                if cube_number > MAX_BLUE_CUBES {
-                    return false;
-                }
-            }
+pub struct Game {
+    id: i64,
+    rounds: Vec<Vec<Cube>>,
+}
+
+impl Game {
+    pub fn new(line: &str) -> Self {
+        let id = line.split(':').collect::<Vec<&str>>()[0]
+            .split(' ')
+            .collect::<Vec<&str>>()[1]
+            .parse::<i64>()
+            .unwrap_or(0);
+
+        // Cubes that can't be parsed are ignored.
+        let rounds = game_rounds(line)
+            .map(|round| {
+                round
+                    .split(',')
+                    .map(|cube| cube.trim())
+                    .filter_map(|cube| Cube::new(cube).ok())
+                    .collect::<Vec<Cube>>()
+            })
+            .collect();
+
+        Self { id, rounds }
+    }
+}
+
+pub struct GameRecord {
+    games: Vec<Game>,
+}
+
+fn is_round_possible(round: &[Cube]) -> bool {
+    for cube in round {
+        match cube.color {
+            CubeColor::Red if cube.count > MAX_RED_CUBES => return false,
+            CubeColor::Green if cube.count > MAX_GREEN_CUBES => return false,
+            CubeColor::Blue if cube.count > MAX_BLUE_CUBES => return false,
             _ => {}
         }
     }
@@ -128,94 +167,126 @@ fn is_round_possible(game: &str) -> bool {
 }
 
 fn game_rounds(line: &str) -> impl Iterator<Item = &str> {
-    return line
-        .split(':')
+    line.split(':')
         .map(|line| line.trim())
         .collect::<Vec<&str>>()[1]
         .split(';')
-        .map(|line| line.trim());
+        .map(|line| line.trim())
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
-    let mut sum_of_possible_game_ids: i64 = 0;
+pub fn parse(input: &str) -> Result<GameRecord, SolveError> {
+    Ok(GameRecord {
+        games: input.split_terminator('\n').map(Game::new).collect(),
+    })
+}
 
-    for line in input.split_terminator('\n') {
-        let game_id = line.split(':').collect::<Vec<&str>>()[0]
-            .split(' ')
-            .collect::<Vec<&str>>()[1]
-            .parse::<i64>()
-            .unwrap_or(0);
+fn validate_line(scanner: &mut LineScanner) -> Result<(), SolveError> {
+    scanner.literal("Game ")?;
+    scanner.number()?;
+    scanner.literal(": ")?;
+
+    loop {
+        scanner.number()?;
+        scanner.literal(" ")?;
+
+        let column = scanner.column();
+        match scanner.word() {
+            "red" | "green" | "blue" => {}
+            "" => {
+                return Err(scanner.error(format!(
+                    "Expected a color (red, green or blue), found {}",
+                    scanner.found()
+                )))
+            }
+            color => {
+                return Err(scanner.error_at(
+                    column,
+                    format!("Expected a color (red, green or blue), found {:?}", color),
+                ))
+            }
+        }
 
-        let mut possible_game = true;
+        match scanner.peek() {
+            Some(',') => scanner.literal(", ")?,
+            Some(';') => scanner.literal("; ")?,
+            _ => return scanner.end(),
+        }
+    }
+}
 
-        for round in game_rounds(line) {
-            possible_game = is_round_possible(round);
+// "Game <id>: <count> <color>, ...; ..." lines, with the rounds separated by ';'.
+pub fn validate(input: &str) -> Vec<SolveError> {
+    validate_lines(input, validate_line)
+}
 
-            if !possible_game {
-                break;
-            }
-        }
+// "Game <id>:" lines.
+pub fn fingerprint(input: &str) -> bool {
+    all_lines_match(input, |line| {
+        line.strip_prefix("Game ")
+            .and_then(|line| line.split_once(':'))
+            .is_some_and(|(game_id, _)| {
+                !game_id.is_empty() && game_id.bytes().all(|byte| byte.is_ascii_digit())
+            })
+    })
+}
+
+pub static FINGERPRINT: Option<FingerprintFn> = Some(fingerprint);
 
-        if possible_game {
-            sum_of_possible_game_ids += game_id;
+pub fn part1(record: &GameRecord) -> Result<Answer, SolveError> {
+    let mut sum_of_possible_game_ids: i64 = 0;
+
+    for game in &record.games {
+        if game.rounds.iter().all(|round| is_round_possible(round)) {
+            sum_of_possible_game_ids += game.id;
         }
     }
 
-    Ok(sum_of_possible_game_ids.to_string())
+    Ok(Answer::from(sum_of_possible_game_ids))
 }
 
-fn power_of_set_of_cubes<'a>(rounds: impl Iterator<Item = &'a str>) -> i64 {
+fn power_of_set_of_cubes(rounds: &[Vec<Cube>]) -> i64 {
     let mut min_red_cubes: i64 = 0;
     let mut min_green_cubes: i64 = 0;
     let mut min_blue_cubes: i64 = 0;
 
-    for round in rounds {
-        for single_cube in round.split(',').map(|line| line.trim()) {
-            match Cube::new(single_cube) {
-                Ok(cube) => match cube.color {
-                    CubeColor::Red => {
-                        if cube.count > min_red_cubes {
-                            min_red_cubes = cube.count
-                        }
-                    }
-                    CubeColor::Green => {
-                        if cube.count > min_green_cubes {
-                            min_green_cubes = cube.count
-                        }
-                    }
-                    CubeColor::Blue => {
-                        if cube.count > min_blue_cubes {
-                            min_blue_cubes = cube.count
-                        }
-                    }
-                },
-                Err(_) => continue,
-            };
+    for cube in rounds.iter().flatten() {
+        match cube.color {
+            CubeColor::Red => {
+                if cube.count > min_red_cubes {
+                    min_red_cubes = cube.count
+                }
+            }
+            CubeColor::Green => {
+                if cube.count > min_green_cubes {
+                    min_green_cubes = cube.count
+                }
+            }
+            CubeColor::Blue => {
+                if cube.count > min_blue_cubes {
+                    min_blue_cubes = cube.count
+                }
+            }
         }
     }
 
     min_red_cubes * min_green_cubes * min_blue_cubes
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
+pub fn part2(record: &GameRecord) -> Result<Answer, SolveError> {
     let mut sum_of_powers: i64 = 0;
 
-    for line in input.split_terminator('\n') {
-        sum_of_powers += power_of_set_of_cubes(game_rounds(line))
+    for game in &record.games {
+        sum_of_powers += power_of_set_of_cubes(&game.rounds)
     }
 
-    Ok(sum_of_powers.to_string())
+    Ok(Answer::from(sum_of_powers))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day2::{part1, part2};
-
-    static TEST_INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+    use crate::answer::Answer;
+    use crate::day2::{fingerprint, parse, part1, part2, validate, EXAMPLE_INPUT};
+    use crate::error::SolveError;
 
     static TEST_INPUT_WITH_INVALID_GAME_IDS: &str =
         "Game x: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
@@ -239,46 +310,102 @@ Game x: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
     static TEST_INPUT_INVALID_CUBE_COLOR: &str =
         "Game 1: 3 blue, 4 purple; 1 red, 2 green, 6 blue; 2 green";
 
+    #[test]
+    fn test_fingerprint() {
+        assert!(fingerprint(EXAMPLE_INPUT));
+        assert!(fingerprint(TEST_INPUT_WITH_INVALID_CUBE_COLOR));
+        assert!(!fingerprint(TEST_INPUT_WITH_INVALID_GAME_IDS));
+        assert!(!fingerprint("Card 1: 41 48 | 83 86\n"));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate(EXAMPLE_INPUT), vec![]);
+        assert_eq!(
+            validate(TEST_INPUT_WITH_INVALID_GAME_IDS),
+            vec![
+                SolveError::invalid_input("Expected a number, found 'x'").at(1, 6),
+                SolveError::invalid_input("Expected a number, found 'x'").at(5, 6),
+            ]
+        );
+        assert_eq!(
+            validate(TEST_INPUT_WITH_INVALID_CUBE_COUNT),
+            vec![SolveError::invalid_input("Expected a number, found 'X'").at(1, 26)]
+        );
+        assert_eq!(
+            validate(TEST_INPUT_WITH_INVALID_CUBE_COLOR),
+            vec![SolveError::invalid_input(
+                "Expected a color (red, green or blue), found \"purple\""
+            )
+            .at(1, 29)]
+        );
+        assert_eq!(
+            validate("Game 1: 3 blue, 4 red;\nGame 2 1 blue\n"),
+            vec![
+                SolveError::invalid_input("Expected \"; \", found ';'").at(1, 22),
+                SolveError::invalid_input("Expected \": \", found ' '").at(2, 7),
+            ]
+        );
+    }
+
     #[test]
     fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), Ok(8.to_string()));
+        assert_eq!(part1(&parse(EXAMPLE_INPUT).unwrap()), Ok(Answer::from(8)));
     }
 
     #[test]
     fn test_part1_with_invalid_game_ids() {
-        assert_eq!(part1(TEST_INPUT_WITH_INVALID_GAME_IDS), Ok(2.to_string()));
+        assert_eq!(
+            part1(&parse(TEST_INPUT_WITH_INVALID_GAME_IDS).unwrap()),
+            Ok(Answer::from(2))
+        );
     }
 
     #[test]
     fn test_part1_with_invalid_cube_count() {
-        assert_eq!(part1(TEST_INPUT_WITH_INVALID_CUBE_COUNT), Ok(3.to_string()));
+        assert_eq!(
+            part1(&parse(TEST_INPUT_WITH_INVALID_CUBE_COUNT).unwrap()),
+            Ok(Answer::from(3))
+        );
     }
 
     #[test]
     fn test_part1_with_invalid_cube_color() {
-        assert_eq!(part1(TEST_INPUT_WITH_INVALID_CUBE_COLOR), Ok(3.to_string()));
+        assert_eq!(
+            part1(&parse(TEST_INPUT_WITH_INVALID_CUBE_COLOR).unwrap()),
+            Ok(Answer::from(3))
+        );
     }
 
     #[test]
     fn test_part1_with_too_many_green_cubes() {
         assert_eq!(
-            part1(TEST_INPUT_WITH_TOO_MANY_GREEN_CUBES),
-            Ok(0.to_string())
+            part1(&parse(TEST_INPUT_WITH_TOO_MANY_GREEN_CUBES).unwrap()),
+            Ok(Answer::from(0))
         );
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(TEST_INPUT), Ok(2286.to_string()));
+        assert_eq!(
+            part2(&parse(EXAMPLE_INPUT).unwrap()),
+            Ok(Answer::from(2286))
+        );
     }
 
     #[test]
     fn test_part2_invalid_value_for_cube_count() {
-        assert_eq!(part2(TEST_INPUT_INVALID_CUBE_COUNT), Ok(48.to_string()));
+        assert_eq!(
+            part2(&parse(TEST_INPUT_INVALID_CUBE_COUNT).unwrap()),
+            Ok(Answer::from(48))
+        );
     }
 
     #[test]
     fn test_part2_invalid_value_for_cube_color() {
-        assert_eq!(part2(TEST_INPUT_INVALID_CUBE_COLOR), Ok(12.to_string()));
+        assert_eq!(
+            part2(&parse(TEST_INPUT_INVALID_CUBE_COLOR).unwrap()),
+            Ok(Answer::from(12))
+        );
     }
 }
@@ -60,23 +60,117 @@ In this schematic, there are two gears. The first is in the top left; it has par
 What is the sum of all of the gear ratios in your engine schematic?
 */
 
-struct Engine<'a> {
+use crate::answer::Answer;
+use crate::error::SolveError;
+use crate::example::Example;
+use crate::solvers::FingerprintFn;
+use crate::validation::{all_lines_match, validate_lines};
+
+static EXAMPLE_INPUT: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+pub static EXAMPLES: &[Example] = &[
+    Example {
+        part: 1,
+        input: EXAMPLE_INPUT,
+        answer: "4361",
+    },
+    Example {
+        part: 2,
+        input: EXAMPLE_INPUT,
+        answer: "467835",
+    },
+];
+
+pub struct Engine {
     line_length: usize,
-    data: &'a [u8],
+    data: Vec<u8>,
 }
 
-impl<'a> Engine<'a> {
-    pub fn new(input: &'a str) -> Result<Self, String> {
+impl Engine {
+    pub fn new(input: &str) -> Result<Self, SolveError> {
         Ok(Self {
             line_length: match input.find('\n') {
                 Some(length) => length,
-                None => return Err("Input has no new line character, exiting...".to_string()),
+                None => {
+                    return Err(SolveError::invalid_input("Input has no new line character")
+                        .at_offset(input, input.len()))
+                }
             },
-            data: input.as_bytes(),
+            data: input.as_bytes().to_vec(),
         })
     }
+
+    // 1-based line and column of the byte at `index`, rows being `line_length` bytes plus a new line.
+    fn position(&self, index: usize) -> (usize, usize) {
+        (
+            index / (self.line_length + 1) + 1,
+            index % (self.line_length + 1) + 1,
+        )
+    }
+}
+
+pub fn parse(input: &str) -> Result<Engine, SolveError> {
+    Engine::new(input)
+}
+
+// Rows of the same length, made of '.', digits and symbols.
+pub fn validate(input: &str) -> Vec<SolveError> {
+    let mut row_length = None;
+
+    validate_lines(input, |scanner| {
+        while let Some(character) = scanner.peek() {
+            if character != '.' && !character.is_ascii_digit() && !character.is_ascii_punctuation()
+            {
+                return Err(scanner.error(format!(
+                    "Unexpected {:?}, expected '.', a digit or a symbol",
+                    character
+                )));
+            }
+            scanner.next_char();
+        }
+
+        let length = scanner.line().len();
+        match row_length {
+            None => {
+                row_length = Some(length);
+                Ok(())
+            }
+            Some(row_length) if row_length == length => Ok(()),
+            Some(row_length) => Err(scanner.error_at(
+                row_length.min(length) + 1,
+                format!(
+                    "Row has {} characters, expected {} like the first row",
+                    length, row_length
+                ),
+            )),
+        }
+    })
+}
+
+// Rectangular grid of '.', digits and symbols.
+pub fn fingerprint(input: &str) -> bool {
+    let mut row_length = None;
+
+    all_lines_match(input, |line| {
+        *row_length.get_or_insert(line.len()) == line.len()
+            && line.chars().all(|character| {
+                character == '.' || character.is_ascii_digit() || character.is_ascii_punctuation()
+            })
+    })
 }
 
+pub static FINGERPRINT: Option<FingerprintFn> = Some(fingerprint);
+
 fn check_bounds(engine: &Engine, index_to_verify: usize) -> Option<usize> {
     if index_to_verify < engine.data.len() {
         return Some(index_to_verify);
@@ -130,12 +224,7 @@ fn is_symbol_around(engine: &Engine, index: usize) -> bool {
     false
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
-    let engine = match Engine::new(input) {
-        Ok(engine) => engine,
-        Err(error) => return Err(error),
-    };
-
+pub fn part1(engine: &Engine) -> Result<Answer, SolveError> {
     let mut sum_of_part_numbers: i64 = 0;
 
     let mut number_first_index: usize = usize::MAX;
@@ -152,7 +241,7 @@ pub fn part1(input: &str) -> Result<String, String> {
                     number_last_index = index;
                 }
 
-                if is_symbol_around(&engine, index) {
+                if is_symbol_around(engine, index) {
                     found_symbol_around = true;
                 }
             }
@@ -169,10 +258,13 @@ pub fn part1(input: &str) -> Result<String, String> {
                             }
                         }
                         Err(error) => {
-                            return Err(format!(
-                                "Couldn't parse {:?} into u32: {:?}",
+                            let (line, column) = engine.position(number_first_index);
+
+                            return Err(SolveError::parse(format!(
+                                "Couldn't parse {:?} into i64: {}",
                                 potential_part_number, error
                             ))
+                            .at(line, column));
                         }
                     }
 
@@ -184,7 +276,7 @@ pub fn part1(input: &str) -> Result<String, String> {
         }
     }
 
-    Ok(sum_of_part_numbers.to_string())
+    Ok(Answer::from(sum_of_part_numbers))
 }
 
 fn part_number(engine: &Engine, index: usize) -> Option<i64> {
@@ -220,12 +312,9 @@ fn part_number(engine: &Engine, index: usize) -> Option<i64> {
         part_number_high_index -= 1;
     }
 
-    match String::from_utf8_lossy(&engine.data[part_number_low_index..=part_number_high_index])
+    String::from_utf8_lossy(&engine.data[part_number_low_index..=part_number_high_index])
         .parse::<i64>()
-    {
-        Ok(part_number) => Some(part_number),
-        Err(_) => None,
-    }
+        .ok()
 }
 
 fn part_numbers_around(engine: &Engine, index: usize) -> Vec<i64> {
@@ -255,17 +344,12 @@ fn part_numbers_around(engine: &Engine, index: usize) -> Vec<i64> {
     part_numbers
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
-    let engine = match Engine::new(input) {
-        Ok(engine) => engine,
-        Err(error) => return Err(error),
-    };
-
+pub fn part2(engine: &Engine) -> Result<Answer, SolveError> {
     let mut sum_of_gear_ratios: i64 = 0;
 
     for (index, byte) in engine.data.iter().enumerate() {
         if byte == &b'*' {
-            let part_numbers_around_potential_gear = part_numbers_around(&engine, index);
+            let part_numbers_around_potential_gear = part_numbers_around(engine, index);
 
             if part_numbers_around_potential_gear.len() > 1 {
                 sum_of_gear_ratios += part_numbers_around_potential_gear.iter().product::<i64>();
@@ -273,41 +357,77 @@ pub fn part2(input: &str) -> Result<String, String> {
         }
     }
 
-    Ok(sum_of_gear_ratios.to_string())
+    Ok(Answer::from(sum_of_gear_ratios))
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::answer::Answer;
     use crate::day3::{
-        check_bounds, is_symbol_around, part1, part2, part_number, part_numbers_around, Engine,
+        check_bounds, fingerprint, is_symbol_around, parse, part1, part2, part_number,
+        part_numbers_around, validate, Engine, EXAMPLE_INPUT,
     };
+    use crate::error::SolveError;
 
-    static TEST_INPUT: &str = "467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598.0
-";
+    #[test]
+    fn test_fingerprint() {
+        assert!(fingerprint(EXAMPLE_INPUT));
+        assert!(!fingerprint("467..114..\n...*.....\n"));
+        assert!(!fingerprint("1abc2\npqr3stu8vwx\n"));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate(EXAMPLE_INPUT), vec![]);
+        assert_eq!(
+            validate("467..114..\n...*.....\n..35a.633.\n......#....\n"),
+            vec![
+                SolveError::invalid_input("Row has 9 characters, expected 10 like the first row")
+                    .at(2, 10),
+                SolveError::invalid_input("Unexpected 'a', expected '.', a digit or a symbol")
+                    .at(3, 5),
+                SolveError::invalid_input("Row has 11 characters, expected 10 like the first row")
+                    .at(4, 11),
+            ]
+        );
+    }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), Ok(4361.to_string()));
+        assert_eq!(
+            part1(&parse(EXAMPLE_INPUT).unwrap()),
+            Ok(Answer::from(4361))
+        );
+    }
+
+    #[test]
+    fn test_part1_with_too_large_part_number() {
+        assert_eq!(
+            part1(&parse("467..114..\n.*99999999999999999999\n").unwrap()),
+            Err(SolveError::parse(
+                "Couldn't parse \"99999999999999999999\" into i64: number too large to fit in target type"
+            )
+            .at(2, 3))
+        );
+    }
+
+    #[test]
+    fn test_engine_without_new_line() {
+        assert_eq!(
+            parse("467..114..").err(),
+            Some(SolveError::invalid_input("Input has no new line character").at(1, 11))
+        );
     }
 
     #[test]
     fn test_check_bounds() {
-        let engine = Engine::new(TEST_INPUT).unwrap();
+        let engine = Engine::new(EXAMPLE_INPUT).unwrap();
         assert_eq!(check_bounds(&engine, 45), Some(45));
     }
 
     #[test]
     fn test_is_symbol_around() {
-        let engine = Engine::new(TEST_INPUT).unwrap();
+        let engine = Engine::new(EXAMPLE_INPUT).unwrap();
         assert!(!is_symbol_around(&engine, 0));
         assert!(!is_symbol_around(&engine, 1));
         assert!(is_symbol_around(&engine, 2));
@@ -323,7 +443,8 @@ mod tests {
 
     #[test]
     fn test_part_number() {
-        let engine = Engine::new(TEST_INPUT).unwrap();
+        // With a number ending on the last character of the input.
+        let engine = Engine::new(&EXAMPLE_INPUT.replace(".664.598..", ".664.598.0")).unwrap();
         assert_eq!(part_number(&engine, 1), Some(467));
         assert_eq!(part_number(&engine, 4), None);
         assert_eq!(part_number(&engine, 5), Some(114));
@@ -333,7 +454,7 @@ mod tests {
 
     #[test]
     fn test_part_numbers_around() {
-        let engine = Engine::new(TEST_INPUT).unwrap();
+        let engine = Engine::new(EXAMPLE_INPUT).unwrap();
         assert_eq!(part_numbers_around(&engine, 14), vec![467, 35]);
         assert_eq!(part_numbers_around(&engine, 47), vec![617]);
         assert_eq!(part_numbers_around(&engine, 93), vec![755, 598]);
@@ -341,6 +462,9 @@ mod tests {
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(TEST_INPUT), Ok(467835.to_string()));
+        assert_eq!(
+            part2(&parse(EXAMPLE_INPUT).unwrap()),
+            Ok(Answer::from(467835))
+        );
     }
 }
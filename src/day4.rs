@@ -70,9 +70,39 @@ Process all of the original and copied scratchcards until no more scratchcards a
 
 use std::collections::HashSet;
 
+use crate::answer::Answer;
+use crate::error::SolveError;
+use crate::example::Example;
+use crate::solvers::FingerprintFn;
+use crate::timeout::CancellationToken;
+use crate::validation::{all_lines_match, validate_lines, LineScanner};
+
+static EXAMPLE_INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+pub static EXAMPLES: &[Example] = &[
+    Example {
+        part: 1,
+        input: EXAMPLE_INPUT,
+        answer: "13",
+    },
+    Example {
+        part: 2,
+        input: EXAMPLE_INPUT,
+        answer: "30",
+    },
+];
+
+// A card whose header isn't "Card <n>" has no number: it still scores points in part 1, but
+// can't be copied in part 2.
 #[derive(PartialEq, Debug)]
 struct Card {
-    number: usize,
+    number: Option<usize>,
     winning_numbers: HashSet<u64>,
     card_numbers: HashSet<u64>,
     wins: usize,
@@ -85,13 +115,9 @@ impl Card {
             None => return None,
         };
 
-        let card_header_len = card_header.len();
-
-        let card_number_parsed_result = card_header[4..card_header_len].trim().parse::<usize>();
-        let card_number = match &card_number_parsed_result {
-            Ok(number) => number,
-            Err(..) => return None,
-        };
+        let card_number = card_header
+            .strip_prefix("Card")
+            .and_then(|card_number| card_number.trim().parse::<usize>().ok());
 
         let (winning_numbers_str, card_numbers_str) = match card_data.trim().split_once('|') {
             Some((wn, cn)) => (wn, cn),
@@ -118,7 +144,7 @@ impl Card {
             .len();
 
         Some(Self {
-            number: *card_number,
+            number: card_number,
             winning_numbers,
             card_numbers,
             wins,
@@ -126,108 +152,223 @@ impl Card {
     }
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
-    let mut points: i64 = 0;
+pub struct ScratchcardPile {
+    cards: Vec<Card>,
+}
 
-    for line in input.split_terminator('\n') {
-        let (_card_header, card_data) = match line.split_once(':') {
-            Some((ch, cd)) => (ch, cd),
-            None => continue,
-        };
-        let (winning_numbers_str, card_numbers_str) = match card_data.trim().split_once('|') {
-            Some((wn, cn)) => (wn, cn),
-            None => continue,
-        };
+// Lines that aren't valid cards are skipped.
+pub fn parse(input: &str) -> Result<ScratchcardPile, SolveError> {
+    Ok(ScratchcardPile {
+        cards: input.split_terminator('\n').filter_map(Card::new).collect(),
+    })
+}
 
-        let winning_numbers: HashSet<i64> = HashSet::from_iter(
-            winning_numbers_str
-                .split_whitespace()
-                .map(|number| number.parse::<i64>().unwrap_or(0))
-                .collect::<Vec<i64>>(),
-        );
+// Numbers each preceded by spaces, until '|' or the end of the line.
+fn validate_numbers(scanner: &mut LineScanner) -> Result<(), SolveError> {
+    let mut count = 0;
 
-        let card_numbers: HashSet<i64> = HashSet::from_iter(
-            card_numbers_str
-                .split_whitespace()
-                .map(|number| number.parse::<i64>().unwrap_or(0))
-                .collect::<Vec<i64>>(),
-        );
+    loop {
+        let spaces = scanner.spaces();
 
-        let number_of_wins = card_numbers
-            .intersection(&winning_numbers)
-            .collect::<Vec<&i64>>()
-            .len();
+        match scanner.peek() {
+            Some('|') | None if count > 0 => return Ok(()),
+            _ if spaces == 0 => {
+                return Err(scanner.error(format!("Expected ' ', found {}", scanner.found())))
+            }
+            _ => {
+                scanner.number()?;
+                count += 1;
+            }
+        }
+    }
+}
+
+// "Card <n>: <winning numbers> | <numbers>" lines, with the cards numbered from 1 in order.
+pub fn validate(input: &str) -> Vec<SolveError> {
+    let mut expected_card_number = 1;
+
+    validate_lines(input, |scanner| {
+        scanner.literal("Card")?;
+        if scanner.spaces() == 0 {
+            return Err(scanner.error(format!("Expected ' ', found {}", scanner.found())));
+        }
+
+        let column = scanner.column();
+        let card_number = scanner.number()?;
+        if card_number != expected_card_number {
+            let error = scanner.error_at(
+                column,
+                format!(
+                    "Expected card {}, found card {}",
+                    expected_card_number, card_number
+                ),
+            );
+            expected_card_number = card_number + 1;
+            return Err(error);
+        }
+        expected_card_number += 1;
+
+        scanner.literal(":")?;
+        validate_numbers(scanner)?;
+        scanner.literal("|")?;
+        validate_numbers(scanner)?;
+        scanner.end()
+    })
+}
+
+// "Card <n>:" lines with a '|' between the two lists of numbers.
+pub fn fingerprint(input: &str) -> bool {
+    all_lines_match(input, |line| {
+        line.strip_prefix("Card ")
+            .and_then(|line| line.split_once(':'))
+            .is_some_and(|(card_number, numbers)| {
+                let card_number = card_number.trim_start();
+                !card_number.is_empty()
+                    && card_number.bytes().all(|byte| byte.is_ascii_digit())
+                    && numbers.contains('|')
+            })
+    })
+}
 
-        if number_of_wins > 0 {
-            points += 2_i64.pow(number_of_wins as u32 - 1);
+pub static FINGERPRINT: Option<FingerprintFn> = Some(fingerprint);
+
+pub fn part1(pile: &ScratchcardPile) -> Result<Answer, SolveError> {
+    let mut points: i64 = 0;
+
+    for card in &pile.cards {
+        if card.wins > 0 {
+            points += 2_i64.pow(card.wins as u32 - 1);
         }
     }
 
-    Ok(points.to_string())
+    Ok(Answer::from(points))
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
-    let highest_card_number = input.lines().count();
+pub fn part2(pile: &ScratchcardPile, token: &CancellationToken) -> Result<Answer, SolveError> {
+    let numbered_cards: Vec<(usize, &Card)> = pile
+        .cards
+        .iter()
+        .filter_map(|card| card.number.map(|number| (number, card)))
+        .collect();
+    let highest_card_number = numbered_cards
+        .iter()
+        .map(|(number, _card)| *number)
+        .max()
+        .unwrap_or(0);
     let mut scratchcards: Vec<u64> = vec![0; highest_card_number + 1];
 
-    for line in input.split_terminator('\n') {
-        match Card::new(line) {
-            Some(card) => {
-                scratchcards[card.number] += 1;
-
-                for _number_of_cards in 0..scratchcards[card.number] {
-                    let mut card_number_to_increase = card.number + 1;
-
-                    for _win in 0..card.wins {
-                        if card_number_to_increase <= highest_card_number {
-                            scratchcards[card_number_to_increase] += 1;
-                        }
-                        card_number_to_increase += 1;
-                    }
-                }
+    for (number, card) in numbered_cards {
+        token.check()?;
+        scratchcards[number] += 1;
+
+        for _number_of_cards in 0..scratchcards[number] {
+            let last_card_number_to_increase = highest_card_number.min(number + card.wins);
+
+            for card_count in scratchcards
+                .iter_mut()
+                .take(last_card_number_to_increase + 1)
+                .skip(number + 1)
+            {
+                *card_count += 1;
             }
-            None => continue,
         }
     }
 
-    Ok(scratchcards.iter().sum::<u64>().to_string())
+    Ok(Answer::from(scratchcards.iter().sum::<u64>()))
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
 
-    use crate::day4::{part1, part2, Card};
+    use crate::answer::Answer;
+    use crate::day4::{fingerprint, parse, part1, part2, validate, Card, EXAMPLE_INPUT};
+    use crate::error::{ErrorKind, SolveError};
+    use crate::timeout::CancellationToken;
 
-    static TEST_INPUT: &str = r#"
-Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 |  4 77 10 23 35 67 36 11
-"#;
+    #[test]
+    fn test_fingerprint() {
+        assert!(fingerprint(EXAMPLE_INPUT));
+        assert!(!fingerprint("Card 1: 41 48 83 86 17\n"));
+        assert!(!fingerprint("Game 1: 3 blue | 4 red\n"));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate(EXAMPLE_INPUT), vec![]);
+        assert_eq!(
+            validate("Card 1: 41 48 | 83 86\nCard 3: 13 32 | 61 30\nCard 4: 1 x | 69 82\nCard 5: 41 92 73\n"),
+            vec![
+                SolveError::invalid_input("Expected card 2, found card 3").at(2, 6),
+                SolveError::invalid_input("Expected a number, found 'x'").at(3, 11),
+                SolveError::invalid_input("Expected \"|\", found the end of the line").at(4, 17),
+            ]
+        );
+        assert_eq!(
+            validate("Card 1:| 83 86\n"),
+            vec![SolveError::invalid_input("Expected ' ', found '|'").at(1, 8)]
+        );
+    }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), Ok(13.to_string()));
+        assert_eq!(part1(&parse(EXAMPLE_INPUT).unwrap()), Ok(Answer::from(13)));
     }
 
     #[test]
     fn test_card() {
         assert_eq!(
-            Card::new(TEST_INPUT.lines().nth(1).unwrap()),
+            Card::new(EXAMPLE_INPUT.lines().next().unwrap()),
             Some(Card {
-                number: 1,
+                number: Some(1),
                 winning_numbers: HashSet::from([41, 48, 83, 86, 17]),
                 card_numbers: HashSet::from([83, 86, 6, 31, 17, 9, 48, 53]),
                 wins: 4
             })
-        )
+        );
+        assert_eq!(
+            Card::new("C: 41 48 | 48 53"),
+            Some(Card {
+                number: None,
+                winning_numbers: HashSet::from([41, 48]),
+                card_numbers: HashSet::from([48, 53]),
+                wins: 1
+            })
+        );
+        assert_eq!(Card::new("Card 1: 41 48"), None);
+    }
+
+    #[test]
+    fn test_part1_ignores_card_headers() {
+        assert_eq!(
+            part1(
+                &parse(
+                    "C: 41 48 | 48 53
+Card x: 41 48 | 41 48
+"
+                )
+                .unwrap()
+            ),
+            Ok(Answer::from(3))
+        );
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(TEST_INPUT), Ok(30.to_string()));
+        assert_eq!(
+            part2(&parse(EXAMPLE_INPUT).unwrap(), &CancellationToken::new()),
+            Ok(Answer::from(30))
+        );
+    }
+
+    #[test]
+    fn test_part2_cancelled() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        assert_eq!(
+            part2(&parse(EXAMPLE_INPUT).unwrap(), &token).map_err(|error| error.kind),
+            Err(ErrorKind::Timeout)
+        );
     }
 }
@@ -0,0 +1,163 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    Io,
+    Parse,
+    InvalidInput,
+    Unsupported,
+    Panic,
+    Timeout,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::Io => write!(f, "I/O error"),
+            ErrorKind::Parse => write!(f, "Parse error"),
+            ErrorKind::InvalidInput => write!(f, "Invalid input"),
+            ErrorKind::Unsupported => write!(f, "Unsupported"),
+            ErrorKind::Panic => write!(f, "Panic"),
+            ErrorKind::Timeout => write!(f, "Timeout"),
+        }
+    }
+}
+
+// Error returned by the solvers, with the 1-based position in the puzzle input when known.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolveError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl SolveError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn io(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Io, message)
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Parse, message)
+    }
+
+    pub fn invalid_input(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::InvalidInput, message)
+    }
+
+    pub fn unsupported(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Unsupported, message)
+    }
+
+    pub fn panic(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Panic, message)
+    }
+
+    pub fn timeout(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Timeout, message)
+    }
+
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line);
+        self.column = Some(column);
+        self
+    }
+
+    // Position of the byte at `offset` in `input`, with the column counted in characters.
+    pub fn at_offset(self, input: &str, offset: usize) -> Self {
+        let before = &input[..offset.min(input.len())];
+        let line_start = match before.rfind('\n') {
+            Some(newline_index) => newline_index + 1,
+            None => 0,
+        };
+
+        self.at(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(
+                f,
+                "{} at line {}, column {}: {}",
+                self.kind, line, column, self.message
+            ),
+            (Some(line), None) => write!(f, "{} at line {}: {}", self.kind, line, self.message),
+            _ => write!(f, "{}: {}", self.kind, self.message),
+        }
+    }
+}
+
+impl error::Error for SolveError {}
+
+impl From<io::Error> for SolveError {
+    fn from(error: io::Error) -> Self {
+        SolveError::io(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::error::{ErrorKind, SolveError};
+
+    #[test]
+    fn test_at_offset() {
+        let input = "467..114..\n...*......\n..35..633.";
+
+        assert_eq!(
+            SolveError::parse("x").at_offset(input, 0),
+            SolveError::parse("x").at(1, 1)
+        );
+        assert_eq!(
+            SolveError::parse("x").at_offset(input, 14),
+            SolveError::parse("x").at(2, 4)
+        );
+        assert_eq!(
+            SolveError::parse("x").at_offset(input, 10),
+            SolveError::parse("x").at(1, 11)
+        );
+        assert_eq!(
+            SolveError::parse("x").at_offset("é1\nb", 2),
+            SolveError::parse("x").at(1, 2)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            SolveError::invalid_input("Input has no new line character").to_string(),
+            "Invalid input: Input has no new line character"
+        );
+        assert_eq!(
+            SolveError::parse("Couldn't parse \"12a\"")
+                .at(3, 7)
+                .to_string(),
+            "Parse error at line 3, column 7: Couldn't parse \"12a\""
+        );
+    }
+
+    #[test]
+    fn test_from_io_error() {
+        let error = SolveError::from(io::Error::new(io::ErrorKind::NotFound, "missing"));
+
+        assert_eq!(error.kind, ErrorKind::Io);
+        assert_eq!(error.message, "missing");
+    }
+}
@@ -0,0 +1,54 @@
+use crate::answer::Answer;
+use crate::answers::Verdict;
+
+// Example given in a puzzle statement, with the answer it has for a part.
+#[derive(Debug)]
+pub struct Example {
+    pub part: u8,
+    pub input: &'static str,
+    pub answer: &'static str,
+}
+
+impl Example {
+    pub fn verify<E>(&self, result: &Result<Answer, E>) -> Verdict {
+        let Ok(expected) = self.answer.parse::<Answer>();
+
+        match result {
+            Ok(answer) if *answer == expected => Verdict::Pass,
+            _ => Verdict::Fail { expected },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::answers::Verdict;
+    use crate::example::Example;
+
+    static EXAMPLE: Example = Example {
+        part: 1,
+        input: "1abc2\n",
+        answer: "12",
+    };
+
+    #[test]
+    fn test_verify() {
+        assert_eq!(
+            EXAMPLE.verify::<String>(&Ok(Answer::from(12_u32))),
+            Verdict::Pass
+        );
+        assert_eq!(
+            EXAMPLE.verify::<String>(&Ok(Answer::from(21_u32))),
+            Verdict::Fail {
+                expected: Answer::from(12_u32)
+            }
+        );
+        assert_eq!(
+            EXAMPLE.verify(&Err("Invalid input".to_string())),
+            Verdict::Fail {
+                expected: Answer::from(12_u32)
+            }
+        );
+    }
+}
@@ -0,0 +1,415 @@
+use std::collections::BTreeMap;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::answer::Answer;
+use crate::error::SolveError;
+use crate::line_file;
+
+// One solver run, stored as
+// "<timestamp> <revision> <profile> <day> <part> <answer hash> <elapsed ns>". The revision is "-"
+// outside of a git repository and the answer hash is "-" for a failed run. The profile is the
+// build profile the run was timed with, "debug" or "release".
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    pub timestamp: u64,
+    pub revision: Option<String>,
+    pub profile: String,
+    pub day: u8,
+    pub part: u8,
+    pub answer_hash: Option<u64>,
+    pub elapsed: Duration,
+}
+
+impl HistoryEntry {
+    fn parse(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+
+        match fields[..] {
+            [timestamp, revision, profile, day, part, answer_hash, elapsed] => Some(Self {
+                timestamp: timestamp.parse().ok()?,
+                revision: match revision {
+                    "-" => None,
+                    revision => Some(revision.to_string()),
+                },
+                profile: profile.to_string(),
+                day: day.parse().ok()?,
+                part: part.parse().ok()?,
+                answer_hash: match answer_hash {
+                    "-" => None,
+                    answer_hash => Some(u64::from_str_radix(answer_hash, 16).ok()?),
+                },
+                elapsed: Duration::from_nanos(elapsed.parse().ok()?),
+            }),
+            _ => None,
+        }
+    }
+
+    fn line(&self) -> String {
+        format!(
+            "{} {} {} {} {} {} {}",
+            self.timestamp,
+            self.revision.as_deref().unwrap_or("-"),
+            self.profile,
+            self.day,
+            self.part,
+            match self.answer_hash {
+                Some(answer_hash) => format!("{:016x}", answer_hash),
+                None => "-".to_string(),
+            },
+            self.elapsed.as_nanos()
+        )
+    }
+}
+
+// 64-bit FNV-1a, so answers can be compared across runs without being stored.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+pub fn answer_hash(result: &Result<Answer, SolveError>) -> Option<u64> {
+    result
+        .as_ref()
+        .ok()
+        .map(|answer| fnv1a(answer.to_string().as_bytes()))
+}
+
+// Short hash of the checked out commit, when running from a git repository.
+pub fn git_revision() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    let revision = String::from_utf8(output.stdout).ok()?.trim().to_string();
+
+    match output.status.success() && !revision.is_empty() {
+        true => Some(revision),
+        false => None,
+    }
+}
+
+// Build profile of this binary, as stored in the history.
+pub fn build_profile() -> &'static str {
+    match cfg!(debug_assertions) {
+        true => "debug",
+        false => "release",
+    }
+}
+
+// Seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.as_secs())
+        .unwrap_or(0)
+}
+
+// UTC date and time of a timestamp, e.g. "2023-12-04 05:00:00".
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let seconds = timestamp % 86_400;
+
+    // Civil date from the number of days since 1970-01-01, in eras of 400 years from 0000-03-01.
+    let shifted_days = days + 719_468;
+    let era = shifted_days.div_euclid(146_097);
+    let day_of_era = shifted_days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = match shifted_month < 10 {
+        true => shifted_month + 3,
+        false => shifted_month - 9,
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3_600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+// Runs of the solvers, in the order they were recorded.
+#[derive(Debug, Default, PartialEq)]
+pub struct History {
+    pub entries: Vec<HistoryEntry>,
+}
+
+// Runs of one solver built with one profile, with the first run that changed the answer and the
+// first run slower than the median of the runs before it by more than the threshold. Times of
+// failed runs are ignored.
+#[derive(Debug, PartialEq)]
+pub struct Trend<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub profile: &'a str,
+    pub runs: Vec<&'a HistoryEntry>,
+    pub answer_change: Option<&'a HistoryEntry>,
+    pub regression: Option<(&'a HistoryEntry, Duration)>,
+}
+
+impl Trend<'_> {
+    fn times(&self) -> impl Iterator<Item = Duration> + '_ {
+        self.runs
+            .iter()
+            .filter(|run| run.answer_hash.is_some())
+            .map(|run| run.elapsed)
+    }
+
+    pub fn first(&self) -> Option<Duration> {
+        self.times().next()
+    }
+
+    pub fn latest(&self) -> Option<Duration> {
+        self.times().last()
+    }
+
+    pub fn best(&self) -> Option<Duration> {
+        self.times().min()
+    }
+}
+
+fn median(durations: &[Duration]) -> Duration {
+    let mut durations = durations.to_vec();
+    durations.sort();
+    durations[durations.len() / 2]
+}
+
+impl History {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut entries = Vec::new();
+
+        line_file::parse_lines(content, |line| {
+            match HistoryEntry::parse(line) {
+            Some(entry) => {
+                entries.push(entry);
+                Ok(())
+            }
+            None => Err(format!(
+                "expected '<timestamp> <revision> <profile> <day> <part> <answer hash> <elapsed ns>', found {:?}",
+                line
+            )),
+        }
+        })?;
+
+        Ok(Self { entries })
+    }
+
+    // A missing history file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        Self::parse(&line_file::read(path)?)
+    }
+
+    // Add runs at the end of the history file, creating it if needed.
+    pub fn append(path: &Path, entries: &[HistoryEntry]) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        for entry in entries {
+            writeln!(file, "{}", entry.line())?;
+        }
+        Ok(())
+    }
+
+    // Trend of every solver and build profile in the history, sorted by day, part and profile.
+    // Debug and release times are never compared to each other.
+    pub fn trends(&self, threshold_percent: f64) -> Vec<Trend<'_>> {
+        let mut runs_by_solver: BTreeMap<(u8, u8, &str), Vec<&HistoryEntry>> = BTreeMap::new();
+
+        for entry in &self.entries {
+            runs_by_solver
+                .entry((entry.day, entry.part, &entry.profile))
+                .or_default()
+                .push(entry);
+        }
+
+        runs_by_solver
+            .into_iter()
+            .map(|((day, part, profile), runs)| {
+                // Failed runs have no answer to compare.
+                let answered_runs: Vec<&HistoryEntry> = runs
+                    .iter()
+                    .copied()
+                    .filter(|run| run.answer_hash.is_some())
+                    .collect();
+                let answer_change = answered_runs
+                    .windows(2)
+                    .find(|pair| pair[0].answer_hash != pair[1].answer_hash)
+                    .map(|pair| pair[1]);
+
+                let mut earlier_times = Vec::new();
+                let mut regression = None;
+
+                for run in runs.iter().filter(|run| run.answer_hash.is_some()) {
+                    if !earlier_times.is_empty() {
+                        let median = median(&earlier_times);
+
+                        if run.elapsed.as_secs_f64()
+                            > median.as_secs_f64() * (1.0 + threshold_percent / 100.0)
+                        {
+                            regression = Some((*run, median));
+                            break;
+                        }
+                    }
+                    earlier_times.push(run.elapsed);
+                }
+
+                Trend {
+                    day,
+                    part,
+                    profile,
+                    runs,
+                    answer_change,
+                    regression,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::answer::Answer;
+    use crate::error::SolveError;
+    use crate::history::{answer_hash, fnv1a, format_timestamp, History, HistoryEntry};
+    use crate::temp_dir::TempDir;
+
+    fn entry(timestamp: u64, part: u8, answer_hash: Option<u64>, elapsed_us: u64) -> HistoryEntry {
+        HistoryEntry {
+            timestamp,
+            revision: Some("0a11664".to_string()),
+            profile: "release".to_string(),
+            day: 4,
+            part,
+            answer_hash,
+            elapsed: Duration::from_micros(elapsed_us),
+        }
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(
+            answer_hash(&Ok(Answer::from(142))),
+            Some(fnv1a("142".as_bytes()))
+        );
+        assert_eq!(answer_hash(&Err(SolveError::parse("x"))), None);
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00");
+        assert_eq!(format_timestamp(1_701_666_000), "2023-12-04 05:00:00");
+        assert_eq!(format_timestamp(1_709_208_000), "2024-02-29 12:00:00");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            History::parse("# History\n1701666000 0a11664 release 4 2 00000000000000ff 3500000\n1701666060 - release 4 2 - 20\n"),
+            Ok(History {
+                entries: vec![
+                    entry(1_701_666_000, 2, Some(255), 3_500),
+                    HistoryEntry {
+                        revision: None,
+                        elapsed: Duration::from_nanos(20),
+                        ..entry(1_701_666_060, 2, None, 0)
+                    }
+                ]
+            })
+        );
+        assert_eq!(
+            History::parse("1701666000 0a11664 4 2 ff 3500000"),
+            Err("Line 1: expected '<timestamp> <revision> <profile> <day> <part> <answer hash> <elapsed ns>', found \"1701666000 0a11664 4 2 ff 3500000\"".to_string())
+        );
+    }
+
+    #[test]
+    fn test_append() {
+        let temp_dir = TempDir::new("history");
+        let path = temp_dir.join("history.txt");
+        let entries = vec![entry(1, 1, Some(1), 10), entry(2, 2, None, 20)];
+
+        History::append(&path, &entries[..1]).unwrap();
+        History::append(&path, &entries[1..]).unwrap();
+
+        assert_eq!(History::load(&path), Ok(History { entries }));
+    }
+
+    #[test]
+    fn test_trends() {
+        let history = History {
+            entries: vec![
+                entry(1, 1, Some(1), 100),
+                entry(1, 2, Some(7), 3_000),
+                entry(2, 1, Some(1), 115),
+                entry(2, 2, Some(7), 3_100),
+                entry(3, 1, Some(2), 110),
+                entry(3, 2, Some(7), 4_000),
+                entry(4, 1, Some(3), 100),
+                entry(5, 1, None, 0),
+                entry(6, 2, None, 0),
+                entry(7, 2, Some(7), 3_000),
+            ],
+        };
+
+        let trends = history.trends(20.0);
+
+        assert_eq!(trends.len(), 2);
+        assert_eq!(trends[0].runs.len(), 5);
+        assert_eq!(trends[1].runs.len(), 5);
+        assert_eq!(trends[0].answer_change, Some(&history.entries[4]));
+        assert_eq!(trends[0].regression, None);
+        assert_eq!(trends[0].best(), Some(Duration::from_micros(100)));
+        assert_eq!(trends[0].latest(), Some(Duration::from_micros(100)));
+        assert_eq!(trends[1].answer_change, None);
+        assert_eq!(
+            trends[1].regression,
+            Some((&history.entries[5], Duration::from_micros(3_100)))
+        );
+        assert_eq!(trends[1].first(), Some(Duration::from_micros(3_000)));
+        assert_eq!(trends[1].latest(), Some(Duration::from_micros(3_000)));
+    }
+
+    #[test]
+    fn test_trends_by_profile() {
+        let debug_entry = |timestamp, elapsed_us| HistoryEntry {
+            profile: "debug".to_string(),
+            ..entry(timestamp, 2, Some(7), elapsed_us)
+        };
+        let history = History {
+            entries: vec![
+                entry(1, 2, Some(7), 3_000),
+                debug_entry(2, 40_000),
+                entry(3, 2, Some(7), 3_100),
+                debug_entry(4, 41_000),
+            ],
+        };
+
+        let trends = history.trends(20.0);
+
+        assert_eq!(trends.len(), 2);
+        assert_eq!(trends[0].profile, "debug");
+        assert_eq!(
+            trends[0].runs,
+            vec![&history.entries[1], &history.entries[3]]
+        );
+        assert_eq!(trends[0].regression, None);
+        assert_eq!(trends[1].profile, "release");
+        assert_eq!(trends[1].regression, None);
+        assert_eq!(trends[1].best(), Some(Duration::from_micros(3_000)));
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+// Run `run_job` on every job with `workers` threads. `on_result` gets the results in the order of
+// the jobs, as soon as every earlier job is done, even when later jobs finish first.
+pub fn run_in_order<J, R, F, D>(jobs: &[J], workers: usize, run_job: F, mut on_result: D)
+where
+    J: Sync,
+    R: Send,
+    F: Fn(&J) -> R + Sync,
+    D: FnMut(R),
+{
+    let next_job = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..workers.max(1).min(jobs.len()) {
+            let sender = sender.clone();
+            let next_job = &next_job;
+            let run_job = &run_job;
+            scope.spawn(move || loop {
+                let job_index = next_job.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(job_index) else {
+                    break;
+                };
+                if sender.send((job_index, run_job(job))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending: BTreeMap<usize, R> = BTreeMap::new();
+        let mut next_job_index = 0;
+
+        for (job_index, result) in receiver {
+            pending.insert(job_index, result);
+
+            while let Some(result) = pending.remove(&next_job_index) {
+                on_result(result);
+                next_job_index += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::jobs::run_in_order;
+
+    #[test]
+    fn test_run_in_order_when_later_jobs_finish_first() {
+        let jobs: Vec<u64> = vec![0, 1, 2, 3];
+        let finished = Mutex::new(Vec::new());
+        let mut results = Vec::new();
+
+        run_in_order(
+            &jobs,
+            4,
+            |job| {
+                thread::sleep(Duration::from_millis((4 - job) * 40));
+                finished.lock().unwrap().push(*job);
+                job * 10
+            },
+            |result| results.push(result),
+        );
+
+        assert_eq!(*finished.lock().unwrap(), vec![3, 2, 1, 0]);
+        assert_eq!(results, vec![0, 10, 20, 30]);
+    }
+
+    #[test]
+    fn test_run_in_order_with_one_worker() {
+        let jobs = ["a", "b", "c"];
+        let mut results = Vec::new();
+
+        run_in_order(
+            &jobs,
+            1,
+            |job| job.to_uppercase(),
+            |result| results.push(result),
+        );
+
+        assert_eq!(results, vec!["A", "B", "C"]);
+
+        run_in_order(&[] as &[&str], 4, |job| job.len(), |_| unreachable!());
+    }
+}
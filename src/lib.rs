@@ -1,4 +1,27 @@
+pub mod allocation;
+pub mod answer;
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod client;
 pub mod day1;
 pub mod day2;
 pub mod day3;
 pub mod day4;
+pub mod error;
+pub mod example;
+pub mod history;
+pub mod jobs;
+pub mod line_file;
+pub mod panic;
+pub mod puzzle_input;
+pub mod report;
+pub mod scaffold;
+pub mod solvers;
+pub mod submission;
+pub mod timeout;
+pub mod validation;
+pub mod watch;
+
+#[cfg(test)]
+mod temp_dir;
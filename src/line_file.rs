@@ -0,0 +1,74 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Give every entry line of a line-based file to `parse_line`, skipping empty lines and lines
+// starting with '#'. The first error is returned with its line number.
+pub fn parse_lines(
+    content: &str,
+    mut parse_line: impl FnMut(&str) -> Result<(), String>,
+) -> Result<(), String> {
+    for (line_index, line) in content.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Err(error) = parse_line(line) {
+            return Err(format!("Line {}: {}", line_index + 1, error));
+        }
+    }
+
+    Ok(())
+}
+
+// Content of a line-based file, where a missing file has no entries.
+pub fn read(path: &Path) -> Result<String, String> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(content),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::line_file::{parse_lines, read};
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn test_parse_lines() {
+        let mut lines = Vec::new();
+
+        assert_eq!(
+            parse_lines("# Header\n  1 1 142 \n\n   # Day 2\n2 1 8\n", |line| {
+                lines.push(line.to_string());
+                Ok(())
+            }),
+            Ok(())
+        );
+        assert_eq!(lines, vec!["1 1 142", "2 1 8"]);
+        assert_eq!(
+            parse_lines("1\n\n3\n4", |line| match line {
+                "3" => Err("expected a number other than 3".to_string()),
+                _ => Ok(()),
+            }),
+            Err("Line 3: expected a number other than 3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        let temp_dir = TempDir::new("line-file");
+        let path = temp_dir.join("answers.txt");
+
+        assert_eq!(read(&path), Ok(String::new()));
+
+        fs::write(&path, "1 1 142\n").unwrap();
+        assert_eq!(read(&path), Ok("1 1 142\n".to_string()));
+        assert!(read(&temp_dir).is_err());
+    }
+}
@@ -1,89 +1,1544 @@
-use clap::Parser;
-use std::fs;
-use std::path::PathBuf;
-use std::time::Instant;
+use aoc_rust_2023::allocation::{self, AllocationStats, CountingAllocator};
+use aoc_rust_2023::answer::Answer;
+use aoc_rust_2023::answers::{Answers, Verdict};
+use aoc_rust_2023::baseline::{self, Baseline, Comparison};
+use aoc_rust_2023::bench::{self, BenchConfig, Statistics};
+use aoc_rust_2023::client::{self, Client, Fetched};
+use aoc_rust_2023::error::{ErrorKind, SolveError};
+use aoc_rust_2023::history::{self, History, HistoryEntry};
+use aoc_rust_2023::jobs::run_in_order;
+use aoc_rust_2023::puzzle_input::{self, InputSource};
+use aoc_rust_2023::report::{self, AllocationRecord, BenchRecord, ComparisonRecord, RunRecord};
+use aoc_rust_2023::scaffold;
+use aoc_rust_2023::solvers::{self, InputShape, Model, Solver};
+use aoc_rust_2023::submission::{Outcome, SubmissionLog};
+use aoc_rust_2023::timeout::{self, CancellationToken};
+use aoc_rust_2023::watch::{self, FileWatcher};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
 
-mod day1;
-mod day2;
-mod day3;
-mod day4;
+// Only counts allocations once enabled with --alloc-stats.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 #[derive(Parser)]
 #[command(name = "advent-of-code-2023")]
 #[command(about = "Calculate solutions for Advent of Code 2023 using Rust and the provided input", long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
-    input_path: String,
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Folder with the puzzle input files (dayN.txt).
+    #[arg(required_unless_present = "input")]
+    input_path: Option<String>,
+    /// Day to solve. All days with an input file are solved if omitted.
     #[arg(short, long)]
-    // TODO: Make this parameter optional and if not provided, solve all days.
-    // day: Option<u8>
-    day: u8,
+    day: Option<u8>,
+    /// Part to solve. All parts of the selected day(s) are solved if omitted.
     #[arg(short, long)]
-    // TODO: Make this parameter optional and if not provided, solve all parts of a given day.
-    // part: Option<u8>
-    part: u8,
+    part: Option<u8>,
+    /// Read the puzzle input of the selected day from this file, or from stdin with "-".
+    #[arg(short, long, value_name = "FILE", requires = "day")]
+    input: Option<String>,
+    /// Compare each solution to the expected answers stored in this file.
+    #[arg(long, value_name = "ANSWERS_FILE")]
+    check: Option<PathBuf>,
+    /// Check each solver against the examples of its puzzle statement first, and stop on a mismatch.
+    #[arg(long)]
+    examples: bool,
+    /// Check the puzzle inputs against the strict grammar of their day instead of solving them.
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "watch", "format", "check", "bench", "baseline", "compare", "alloc_stats", "jobs",
+            "timeout",
+        ]
+    )]
+    validate: bool,
+    /// Benchmark each solver with warmup runs and repeated timed iterations.
+    #[arg(long, conflicts_with = "jobs")]
+    bench: bool,
+    /// Time budget for the timed iterations of each solver when benchmarking.
+    #[arg(long, value_name = "MILLISECONDS", default_value_t = 500)]
+    bench_time: u64,
+    /// Benchmark each solver and save its median time to this baseline file.
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["watch", "jobs"])]
+    baseline: Option<PathBuf>,
+    /// Benchmark each solver and compare its median time to this baseline file.
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["watch", "jobs"])]
+    compare: Option<PathBuf>,
+    /// Slowdown from the baseline, in percent, above which a solver fails the comparison.
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        default_value_t = 10.0,
+        requires = "compare"
+    )]
+    max_regression: f64,
+    /// Report the heap allocations made while parsing and solving, next to the timings.
+    #[arg(long)]
+    alloc_stats: bool,
+    /// Number of worker threads solving the days in parallel.
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: u16,
+    /// Stop waiting for a solver after this duration (e.g. 500ms, 10s, 2m) and report a timeout.
+    #[arg(long, value_name = "DURATION", value_parser = timeout::parse_duration)]
+    timeout: Option<Duration>,
+    /// Solve the inputs even when they look like the input of another day.
+    #[arg(long)]
+    no_shape_check: bool,
+    /// Re-solve the selected days whenever their input file changes.
+    #[arg(long, conflicts_with_all = ["format", "jobs"])]
+    watch: bool,
+    /// File where every solver run is appended (history.txt in the input folder by default).
+    #[arg(long, value_name = "FILE")]
+    history: Option<PathBuf>,
+    /// Output format for the results and timings.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+}
+
+impl Cli {
+    // Saving or comparing to a baseline needs the benchmark statistics. Benchmarks run one day at
+    // a time, so they don't compete for the CPU.
+    fn benchmarking(&self) -> bool {
+        self.bench || self.baseline.is_some() || self.compare.is_some()
+    }
 }
 
-fn load_file(filename: PathBuf) -> Option<std::string::String> {
-    let input_filename = filename.as_path().display().to_string();
+#[derive(Subcommand)]
+enum Command {
+    /// Download the puzzle input of a day into the input folder, unless it's already there.
+    Fetch {
+        /// Folder with the puzzle input files (dayN.txt).
+        input_path: PathBuf,
+        /// Day to download.
+        #[arg(short, long)]
+        day: u8,
+        #[command(flatten)]
+        website: WebsiteArgs,
+    },
+    /// Solve a part and submit its answer, unless the same answer was already judged.
+    Submit {
+        /// Folder with the puzzle input files (dayN.txt).
+        input_path: PathBuf,
+        /// Day to submit.
+        #[arg(short, long)]
+        day: u8,
+        /// Part to submit.
+        #[arg(short, long)]
+        part: u8,
+        /// File recording the judged submissions (submissions.txt in the input folder by default).
+        #[arg(long, value_name = "FILE")]
+        submissions: Option<PathBuf>,
+        /// Submit the answer even when the input looks like the input of another day.
+        #[arg(long)]
+        no_shape_check: bool,
+        #[command(flatten)]
+        website: WebsiteArgs,
+    },
+    /// Show the trend of each solver in the run history, with the first answer change and time
+    /// regression.
+    History {
+        /// Folder with the puzzle input files (dayN.txt).
+        input_path: PathBuf,
+        /// Day to show. All days in the history are shown if omitted.
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Part to show. Both parts are shown if omitted.
+        #[arg(short, long)]
+        part: Option<u8>,
+        /// File with the run history (history.txt in the input folder by default).
+        #[arg(long, value_name = "FILE")]
+        history: Option<PathBuf>,
+        /// Flag a run slower than the median of the earlier runs by more than this percentage.
+        #[arg(long, value_name = "PERCENT", default_value_t = 20.0)]
+        threshold: f64,
+    },
+    /// Generate the module of a day from a template and register its solvers.
+    NewDay {
+        /// Day to generate.
+        day: u8,
+        /// Title of the puzzle.
+        #[arg(long)]
+        title: Option<String>,
+        /// Source folder of the crate, with lib.rs and solvers.rs.
+        #[arg(long, value_name = "FOLDER", default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/src"))]
+        src_dir: PathBuf,
+    },
+}
+
+#[derive(Args)]
+struct WebsiteArgs {
+    /// File with the session token, used when the AOC_SESSION environment variable isn't set.
+    #[arg(long, value_name = "FILE")]
+    session_file: Option<PathBuf>,
+    /// Base URL of the Advent of Code website.
+    #[arg(long, value_name = "URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+}
 
-    match fs::read_to_string(filename) {
-        Ok(path) => Some(path),
+impl WebsiteArgs {
+    fn client(&self) -> Client {
+        Client::new(
+            &self.base_url,
+            client::session_token(self.session_file.as_deref()),
+        )
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+fn load_file(source: &InputSource) -> Option<std::string::String> {
+    match source.read() {
+        Ok(content) => Some(content),
         Err(err) => {
-            println!("Could not load input file '{}'. {}", input_filename, err);
+            eprintln!("Could not load input file '{}'. {}", source, err);
+            None
+        }
+    }
+}
+
+fn load_answers(answers_file: &Path) -> Option<Answers> {
+    let content = load_file(&InputSource::File(answers_file.to_path_buf()))?;
+
+    match Answers::parse(&content) {
+        Ok(answers) => Some(answers),
+        Err(error) => {
+            eprintln!(
+                "Could not parse answers file '{}'. {}",
+                answers_file.display(),
+                error
+            );
             None
         }
     }
 }
 
-type SolverFn = fn(&str) -> Result<String, String>;
+struct SolutionRun {
+    solver: &'static Solver,
+    input_file: InputSource,
+    variant: Option<String>,
+    result: Result<Answer, SolveError>,
+    parse_time: Duration,
+    time: Duration,
+    statistics: Option<Statistics>,
+    verdict: Option<Verdict>,
+    // Median time against the baseline, with --compare.
+    comparison: Option<Comparison>,
+    parse_allocations: Option<AllocationStats>,
+    allocations: Option<AllocationStats>,
+}
+
+impl SolutionRun {
+    fn solution(&self) -> String {
+        watch::solution(&self.result)
+    }
+
+    fn title(&self) -> String {
+        match &self.variant {
+            Some(variant) => format!("{} ({})", self.solver.title, variant),
+            None => self.solver.title.to_string(),
+        }
+    }
+
+    fn record(&self) -> RunRecord {
+        RunRecord {
+            day: self.solver.day,
+            part: self.solver.part,
+            answer: self.result.as_ref().ok().map(Answer::to_string),
+            error: self.result.as_ref().err().map(SolveError::to_string),
+            parse_ns: self.parse_time.as_nanos() as u64,
+            elapsed_ns: self.time.as_nanos() as u64,
+            input_file: self.input_file.to_string(),
+            variant: self.variant.clone(),
+            check: self.verdict.as_ref().map(|verdict| verdict.to_string()),
+            bench: self.statistics.as_ref().map(BenchRecord::from),
+            comparison: self.comparison.as_ref().map(|comparison| {
+                let median = self
+                    .statistics
+                    .as_ref()
+                    .map(|statistics| statistics.median)
+                    .unwrap_or_default();
+                ComparisonRecord::new(comparison, median)
+            }),
+            parse_allocations: self.parse_allocations.as_ref().map(AllocationRecord::from),
+            allocations: self.allocations.as_ref().map(AllocationRecord::from),
+        }
+    }
+}
+
+fn allocation_summary(allocations: &Option<AllocationStats>) -> String {
+    match allocations {
+        Some(allocations) => format!(
+            ", Allocations: {}, Allocated: {} bytes, Peak: {} bytes",
+            allocations.allocations, allocations.bytes_allocated, allocations.peak_live_bytes
+        ),
+        None => String::new(),
+    }
+}
+
+// "Day 4", or "Day 4 (alice)" for an input variant.
+fn day_label(day: u8, variant: &Option<String>) -> String {
+    match variant {
+        Some(variant) => format!("Day {} ({})", day, variant),
+        None => format!("Day {}", day),
+    }
+}
+
+fn day_source(cli: &Cli, solver: &Solver) -> InputSource {
+    match (&cli.input, &cli.input_path) {
+        (Some(input), _) => InputSource::from(input.as_str()),
+        (None, Some(input_path)) => {
+            InputSource::File([input_path.as_str(), solver.input_file].iter().collect())
+        }
+        (None, None) => unreachable!(),
+    }
+}
+
+// Refuse a puzzle input that looks like the input of another day, most likely given through a
+// typo, as its answers would be bogus. It's only warned about with --no-shape-check, like an
+// input that looks like no day.
+fn check_input_shape(
+    day: u8,
+    variant: &Option<String>,
+    source: &InputSource,
+    puzzle_input: &str,
+    no_shape_check: bool,
+) -> bool {
+    match solvers::input_shape(day, puzzle_input) {
+        InputShape::Matches | InputShape::Unchecked => true,
+        InputShape::Unrecognised => {
+            eprintln!(
+                "Input of {} '{}' doesn't look like an input of Day {}, solving it anyway",
+                day_label(day, variant),
+                source,
+                day
+            );
+            true
+        }
+        InputShape::LooksLike(other_day) => {
+            eprintln!(
+                "Input of {} '{}' looks like Day {}, {}",
+                day_label(day, variant),
+                source,
+                other_day,
+                match no_shape_check {
+                    true => "solving it anyway",
+                    false => "it was not solved",
+                }
+            );
+            no_shape_check
+        }
+    }
+}
+
+// Puzzle input of a day, to parse and solve with the selected solvers of that day and verify
+// with the expected answers of that input.
+struct DayJob<'a> {
+    solvers: &'a [&'static Solver],
+    source: InputSource,
+    variant: Option<String>,
+    puzzle_input: Arc<str>,
+    answers: Option<Answers>,
+}
+
+struct DayRun {
+    day: u8,
+    variant: Option<String>,
+    parse_error: Option<SolveError>,
+    parse_time: Duration,
+    parse_allocations: Option<AllocationStats>,
+    runs: Vec<SolutionRun>,
+}
+
+impl DayRun {
+    // Time to parse the input and to solve every part.
+    fn time(&self) -> Duration {
+        self.parse_time + self.runs.iter().map(|run| run.time).sum::<Duration>()
+    }
+}
+
+// Run `function` and measure its time and allocations. With a timeout it runs on a worker
+// thread, and a function out of time is reported with the whole budget as its time.
+fn run_measured<T: Send + 'static>(
+    timeout: Option<Duration>,
+    function: impl FnOnce(&CancellationToken) -> Result<T, SolveError> + Send + 'static,
+) -> (Result<T, SolveError>, Duration, AllocationStats) {
+    let measured = move |token: &CancellationToken| {
+        let chrono_start = Instant::now();
+        let (result, allocations) = allocation::measure(|| function(token));
+        (result, chrono_start.elapsed(), allocations)
+    };
+
+    match timeout {
+        None => measured(&CancellationToken::new()),
+        Some(timeout) => match timeout::run_with_timeout(timeout, move |token| measured(&token)) {
+            Ok(measured) => measured,
+            Err(error) => (Err(error), timeout, AllocationStats::default()),
+        },
+    }
+}
+
+fn solve_day(job: &DayJob, cli: &Cli) -> DayRun {
+    let parse = job.solvers[0].parse;
+    let puzzle_input = Arc::clone(&job.puzzle_input);
+    let (model, parse_time, parse_allocations) =
+        run_measured(cli.timeout, move |_| parse(&puzzle_input).map(Arc::new));
+    let parse_allocations = Some(parse_allocations).filter(|_| cli.alloc_stats);
+
+    let mut runs = Vec::new();
+
+    for solver in job.solvers {
+        let (solution_result, chrono_stop, allocations) = match &model {
+            Ok(model) => {
+                let model: Arc<Model> = Arc::clone(model);
+                let solve = solver.solve;
+                run_measured(cli.timeout, move |token| solve(&model, token))
+            }
+            Err(error) => (
+                Err(error.clone()),
+                Duration::ZERO,
+                AllocationStats::default(),
+            ),
+        };
+        let allocations = Some(allocations).filter(|_| cli.alloc_stats);
+
+        // Only the solve is benchmarked, on the model parsed once for the day. A failed solver
+        // isn't benchmarked: there is no time to keep, and out of time it would run without a
+        // time limit.
+        let statistics = match (&model, &solution_result) {
+            (Ok(model), Ok(_)) if cli.benchmarking() => {
+                let config = BenchConfig {
+                    time_budget: Duration::from_millis(cli.bench_time),
+                    ..BenchConfig::default()
+                };
+                let token = CancellationToken::new();
+                Some(bench::run(&config, || (solver.solve)(model, &token)))
+            }
+            _ => None,
+        };
+
+        let verdict = job
+            .answers
+            .as_ref()
+            .map(|answers| answers.verify(solver.day, solver.part, &solution_result));
+
+        runs.push(SolutionRun {
+            solver,
+            input_file: job.source.clone(),
+            variant: job.variant.clone(),
+            result: solution_result,
+            parse_time,
+            time: chrono_stop,
+            statistics,
+            verdict,
+            comparison: None,
+            parse_allocations,
+            allocations,
+        });
+    }
 
-fn solve(day: u8, part: u8) -> Result<SolverFn, (u8, u8)> {
-    match (day, part) {
-        (1, 1) => Ok(day1::part1),
-        (1, 2) => Ok(day1::part2),
-        (2, 1) => Ok(day2::part1),
-        (2, 2) => Ok(day2::part2),
-        (3, 1) => Ok(day3::part1),
-        (3, 2) => Ok(day3::part2),
-        (4, 1) => Ok(day4::part1),
-        (4, 2) => Ok(day4::part2),
-        (_, _) => Err((day, part)),
+    DayRun {
+        day: job.solvers[0].day,
+        variant: job.variant.clone(),
+        parse_error: model.err(),
+        parse_time,
+        parse_allocations,
+        runs,
+    }
+}
+
+// Solve the days on `cli.jobs` worker threads. `on_day_run` gets the results in the order of
+// the jobs, as soon as every earlier job is done.
+fn run_day_jobs(day_jobs: &[DayJob], cli: &Cli, on_day_run: impl FnMut(DayRun)) {
+    run_in_order(
+        day_jobs,
+        usize::from(cli.jobs),
+        |job| solve_day(job, cli),
+        on_day_run,
+    );
+}
+
+fn print_day_run(day_run: &DayRun) {
+    let day_label = day_label(day_run.day, &day_run.variant);
+
+    match &day_run.parse_error {
+        None => println!(
+            "Input of {} parsed, Time: {}μs{}",
+            day_label,
+            day_run.parse_time.as_micros(),
+            allocation_summary(&day_run.parse_allocations)
+        ),
+        Some(error) => eprintln!(
+            "A problem occured to parse the input of {}: {}, Time: {}μs",
+            day_label,
+            error,
+            day_run.parse_time.as_micros()
+        ),
+    }
+
+    for run in &day_run.runs {
+        match (&day_run.parse_error, &run.result) {
+            (_, Ok(solution)) => println!(
+                "Solution of {}, Part {}: {}, Time: {}μs{}",
+                day_label,
+                run.solver.part,
+                solution,
+                run.time.as_micros(),
+                allocation_summary(&run.allocations)
+            ),
+            (None, Err(error)) if error.kind == ErrorKind::Timeout => println!(
+                "Solution of {}, Part {}: TIMEOUT, {}",
+                day_label, run.solver.part, error.message
+            ),
+            (None, Err(error)) => eprintln!(
+                "A problem occured to solve the problem of {}, Part {}: {}, Time: {}μs",
+                day_label,
+                run.solver.part,
+                error,
+                run.time.as_micros()
+            ),
+            (Some(_), Err(_)) => {}
+        }
     }
 }
 
-fn main() {
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+// Solve the selected days on every change of their input file, until interrupted.
+fn watch(cli: &Cli, selected_solvers: &[&'static Solver], answers: Option<&Answers>) -> ExitCode {
+    let mut watched_days = Vec::new();
+
+    for day_solvers in selected_solvers.chunk_by(|left, right| left.day == right.day) {
+        match day_source(cli, day_solvers[0]) {
+            InputSource::File(path) => watched_days.push((day_solvers, path)),
+            InputSource::Stdin => {
+                eprintln!("The standard input can't be watched, use an input file");
+                return ExitCode::from(EXIT_MISSING_INPUT);
+            }
+        }
+    }
+
+    let mut watcher = FileWatcher::new(watched_days.iter().map(|(_, path)| path.clone()).collect());
+    let mut previous_runs: BTreeMap<(u8, u8), SolutionRun> = BTreeMap::new();
+
+    println!(
+        "Watching {} input file(s) for changes, press Ctrl-C to stop\n",
+        watched_days.len()
+    );
+
+    loop {
+        let changed = watcher.changed();
+
+        for (day_solvers, path) in &watched_days {
+            if !changed.contains(path) {
+                continue;
+            }
+
+            let source = InputSource::File(path.clone());
+            let puzzle_input = match load_file(&source) {
+                Some(puzzle_input) => puzzle_input,
+                None => continue,
+            };
+            if !check_input_shape(
+                day_solvers[0].day,
+                &None,
+                &source,
+                &puzzle_input,
+                cli.no_shape_check,
+            ) {
+                println!();
+                continue;
+            }
+            let day_run = solve_day(
+                &DayJob {
+                    solvers: day_solvers,
+                    source,
+                    variant: None,
+                    puzzle_input: puzzle_input.into(),
+                    answers: answers.cloned(),
+                },
+                cli,
+            );
+
+            if !previous_runs.contains_key(&(day_run.day, day_solvers[0].part)) {
+                print_day_run(&day_run);
+            } else {
+                println!(
+                    "Input of Day {} changed, Parse time: {}μs",
+                    day_run.day,
+                    day_run.parse_time.as_micros()
+                );
+                for run in &day_run.runs {
+                    if let Some(previous_run) =
+                        previous_runs.get(&(run.solver.day, run.solver.part))
+                    {
+                        println!(
+                            "Solution of Day {}, Part {}: {}, Time: {}μs ({})",
+                            run.solver.day,
+                            run.solver.part,
+                            watch::solution_change(&previous_run.result, &run.result),
+                            run.time.as_micros(),
+                            watch::time_delta(previous_run.time, run.time)
+                        );
+                    }
+                }
+            }
+            println!();
+
+            for run in day_run.runs {
+                previous_runs.insert((run.solver.day, run.solver.part), run);
+            }
+        }
+
+        thread::sleep(WATCH_INTERVAL);
+    }
+}
+
+const EXIT_VERIFICATION_MISMATCH: u8 = 1;
+const EXIT_MISSING_INPUT: u8 = 3;
+const EXIT_UNSUPPORTED: u8 = 4;
+const EXIT_WEBSITE_ERROR: u8 = 5;
+const EXIT_SCAFFOLD_FAILED: u8 = 6;
+const EXIT_SLOWER_THAN_BASELINE: u8 = 7;
+const EXIT_INVALID_INPUT: u8 = 8;
+
+// Exit code of the first solver error, so scripts can tell malformed inputs from other failures.
+fn error_exit_code(kind: ErrorKind) -> ExitCode {
+    ExitCode::from(match kind {
+        ErrorKind::Io => 10,
+        ErrorKind::Parse => 11,
+        ErrorKind::InvalidInput => 12,
+        ErrorKind::Unsupported => 13,
+        ErrorKind::Panic => 14,
+        ErrorKind::Timeout => 15,
+    })
+}
+
+fn main() -> ExitCode {
     let cli = Cli::parse();
 
-    let chrono_start;
-    let chrono_stop;
-    let solution_result;
-    let mut total_time: u128 = 0;
+    match &cli.command {
+        Some(Command::Fetch {
+            input_path,
+            day,
+            website,
+        }) => return fetch(input_path, *day, website),
+        Some(Command::Submit {
+            input_path,
+            day,
+            part,
+            submissions,
+            no_shape_check,
+            website,
+        }) => {
+            let submissions = match submissions {
+                Some(submissions) => submissions.clone(),
+                None => input_path.join("submissions.txt"),
+            };
+            return submit(
+                input_path,
+                *day,
+                *part,
+                &submissions,
+                *no_shape_check,
+                website,
+            );
+        }
+        Some(Command::History {
+            input_path,
+            day,
+            part,
+            history,
+            threshold,
+        }) => {
+            let history = match history {
+                Some(history) => history.clone(),
+                None => input_path.join("history.txt"),
+            };
+            return show_history(&history, *day, *part, *threshold);
+        }
+        Some(Command::NewDay {
+            day,
+            title,
+            src_dir,
+        }) => {
+            let title = match title {
+                Some(title) => title.clone(),
+                None => format!("Day {}", day),
+            };
+            return new_day(src_dir, *day, &title);
+        }
+        None => {}
+    }
+
+    if cli.alloc_stats {
+        allocation::enable();
+    }
+
+    let answers = match &cli.check {
+        Some(answers_file) => match load_answers(answers_file) {
+            Some(answers) => Some(answers),
+            None => return ExitCode::from(EXIT_MISSING_INPUT),
+        },
+        None => None,
+    };
+
+    let compared_baseline = match &cli.compare {
+        Some(baseline_file) => match load_file(&InputSource::File(baseline_file.clone())) {
+            Some(content) => match Baseline::parse(&content) {
+                Ok(baseline) => Some(baseline),
+                Err(error) => {
+                    eprintln!(
+                        "Could not parse baseline file '{}'. {}",
+                        baseline_file.display(),
+                        error
+                    );
+                    return ExitCode::from(EXIT_MISSING_INPUT);
+                }
+            },
+            None => return ExitCode::from(EXIT_MISSING_INPUT),
+        },
+        None => None,
+    };
+
+    let selected_solvers: Vec<&Solver> = solvers::select(cli.day, cli.part).collect();
+
+    if selected_solvers.is_empty() {
+        match (cli.day, cli.part) {
+            (Some(day), Some(part)) => eprintln!("Unsupported day {} and part {}", day, part),
+            (Some(day), None) => eprintln!("Unsupported day {}", day),
+            (None, Some(part)) => eprintln!("Unsupported part {}", part),
+            (None, None) => eprintln!("No solver available"),
+        }
+        return ExitCode::from(EXIT_UNSUPPORTED);
+    }
 
-    let day_input: PathBuf = [cli.input_path, format!("day{}.txt", cli.day)]
+    if cli.examples {
+        let example_failures = check_examples(&selected_solvers, cli.format);
+
+        if example_failures > 0 {
+            eprintln!(
+                "\n{} example(s) did not match, the puzzle inputs were not solved",
+                example_failures
+            );
+            return ExitCode::from(EXIT_VERIFICATION_MISMATCH);
+        }
+    }
+
+    if cli.watch {
+        return watch(&cli, &selected_solvers, answers.as_ref());
+    }
+
+    let mut day_jobs: Vec<DayJob> = Vec::new();
+    let mut missing_input = false;
+
+    // Solvers are sorted by day so each input is loaded and parsed once for both parts.
+    for day_solvers in selected_solvers.chunk_by(|left, right| left.day == right.day) {
+        let day = day_solvers[0].day;
+        let source = day_source(&cli, day_solvers[0]);
+
+        // Input variants are only looked up in the input folder, not with --input.
+        let variants = match (&cli.input, &cli.input_path) {
+            (None, Some(input_path)) => {
+                match puzzle_input::input_variants(Path::new(input_path), day) {
+                    Ok(variants) => variants,
+                    Err(error) => {
+                        eprintln!(
+                            "Could not list the input variants of Day {} in '{}'. {}",
+                            day, input_path, error
+                        );
+                        missing_input = true;
+                        Vec::new()
+                    }
+                }
+            }
+            _ => Vec::new(),
+        };
+
+        // When solving all days, only the days with an input file are considered. A day with
+        // input variants doesn't need a dayN.txt either.
+        let skipped = matches!(&source, InputSource::File(path)
+            if (cli.day.is_none() || !variants.is_empty()) && !path.exists());
+
+        if !skipped {
+            match load_file(&source) {
+                Some(puzzle_input) => day_jobs.push(DayJob {
+                    solvers: day_solvers,
+                    source,
+                    variant: None,
+                    puzzle_input: puzzle_input.into(),
+                    answers: answers.clone(),
+                }),
+                None => missing_input = true,
+            }
+        }
+
+        for variant in variants {
+            let variant_answers = match &variant.answers_file {
+                Some(answers_file) => match load_answers(answers_file) {
+                    Some(variant_answers) => Some(variant_answers),
+                    None => {
+                        missing_input = true;
+                        continue;
+                    }
+                },
+                None => None,
+            };
+            let source = InputSource::File(variant.input_file);
+
+            match load_file(&source) {
+                Some(puzzle_input) => day_jobs.push(DayJob {
+                    solvers: day_solvers,
+                    source,
+                    variant: Some(variant.name),
+                    puzzle_input: puzzle_input.into(),
+                    answers: variant_answers,
+                }),
+                None => missing_input = true,
+            }
+        }
+    }
+
+    if cli.validate {
+        let invalid_inputs = validate_inputs(&day_jobs);
+
+        if missing_input {
+            return ExitCode::from(EXIT_MISSING_INPUT);
+        }
+        if invalid_inputs > 0 {
+            return ExitCode::from(EXIT_INVALID_INPUT);
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let mut wrong_day_input = false;
+    day_jobs.retain(|job| {
+        let matches = check_input_shape(
+            job.solvers[0].day,
+            &job.variant,
+            &job.source,
+            &job.puzzle_input,
+            cli.no_shape_check,
+        );
+        wrong_day_input |= !matches;
+        matches
+    });
+
+    if day_jobs.is_empty() && wrong_day_input {
+        return match missing_input {
+            true => ExitCode::from(EXIT_MISSING_INPUT),
+            false => ExitCode::from(EXIT_INVALID_INPUT),
+        };
+    }
+
+    let mut runs: Vec<SolutionRun> = Vec::new();
+    let mut total_time = Duration::ZERO;
+
+    let chrono_start = Instant::now();
+    run_day_jobs(&day_jobs, &cli, |day_run| {
+        if cli.format == OutputFormat::Text {
+            print_day_run(&day_run);
+        }
+        total_time += day_run.time();
+        runs.extend(day_run.runs);
+    });
+    let wall_clock_time = chrono_start.elapsed();
+
+    if runs.is_empty() && !missing_input && !wrong_day_input {
+        if let Some(input_path) = &cli.input_path {
+            eprintln!("No puzzle input file found in '{}'", input_path);
+        }
+        missing_input = true;
+    }
+
+    let failures = runs
         .iter()
+        .filter(|run| matches!(run.verdict, Some(Verdict::Fail { .. })))
+        .count();
+
+    // Failed solvers have no benchmark statistics to compare. Like the history, baselines only
+    // follow the main input of each day.
+    if let Some(baseline) = &compared_baseline {
+        for run in runs
+            .iter_mut()
+            .filter(|run| run.variant.is_none() && run.result.is_ok())
+        {
+            run.comparison = run.statistics.as_ref().map(|statistics| {
+                baseline.compare(
+                    run.solver.day,
+                    run.solver.part,
+                    statistics.median,
+                    cli.max_regression,
+                )
+            });
+        }
+    }
+    let slower = runs
+        .iter()
+        .filter(|run| matches!(run.comparison, Some(Comparison::Slower { .. })))
+        .count();
+
+    match cli.format {
+        OutputFormat::Text => {
+            if cli.benchmarking() {
+                print_bench_summary(&runs);
+            } else if cli.alloc_stats {
+                print_allocation_summary(&runs);
+            } else if runs.len() > 1 {
+                print_summary(&runs);
+            }
+
+            println!("\nTotal Time: {}μs", total_time.as_micros());
+            println!(
+                "Wall-clock Time: {}μs with {} job(s)",
+                wall_clock_time.as_micros(),
+                cli.jobs
+            );
+
+            if answers.is_some() || runs.iter().any(|run| run.verdict.is_some()) {
+                print_check(&runs, failures);
+            }
+
+            if compared_baseline.is_some() {
+                print_comparison(&runs, slower, cli.max_regression);
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            report::to_json(&runs.iter().map(SolutionRun::record).collect::<Vec<_>>())
+        ),
+        OutputFormat::Csv => print!(
+            "{}",
+            report::to_csv(&runs.iter().map(SolutionRun::record).collect::<Vec<_>>())
+        ),
+    }
+
+    // Like the input variants, an input given with --input isn't the dayN.txt the history follows.
+    // Runs timed alongside other days, with allocation counting or on a timeout thread aren't
+    // comparable to the other runs, so they aren't recorded either.
+    let history_file = match (&cli.history, &cli.input_path, &cli.input) {
+        _ if cli.jobs > 1 || cli.alloc_stats || cli.timeout.is_some() => None,
+        (_, _, Some(_)) => None,
+        (Some(history_file), _, None) => Some(history_file.clone()),
+        (None, Some(input_path), None) => Some(Path::new(input_path).join("history.txt")),
+        (None, None, None) => None,
+    };
+    if let Some(history_file) =
+        history_file.filter(|_| runs.iter().any(|run| run.variant.is_none()))
+    {
+        record_history(&history_file, &runs);
+    }
+
+    if let Some(baseline_file) = &cli.baseline {
+        match save_baseline(baseline_file, &runs) {
+            Ok(saved) => {
+                if cli.format == OutputFormat::Text {
+                    println!(
+                        "\nSaved the median time of {} solver(s) to baseline file '{}'",
+                        saved,
+                        baseline_file.display()
+                    );
+                }
+            }
+            Err(error) => {
+                eprintln!(
+                    "Could not save baseline file '{}'. {}",
+                    baseline_file.display(),
+                    error
+                );
+                missing_input = true;
+            }
+        }
+    }
+
+    if missing_input {
+        return ExitCode::from(EXIT_MISSING_INPUT);
+    }
+
+    if wrong_day_input {
+        return ExitCode::from(EXIT_INVALID_INPUT);
+    }
+
+    if let Some(error) = runs.iter().find_map(|run| run.result.as_ref().err()) {
+        return error_exit_code(error.kind);
+    }
+
+    if failures > 0 {
+        return ExitCode::from(EXIT_VERIFICATION_MISMATCH);
+    }
+
+    if slower > 0 {
+        return ExitCode::from(EXIT_SLOWER_THAN_BASELINE);
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn fetch(input_path: &Path, day: u8, website: &WebsiteArgs) -> ExitCode {
+    match website.client().fetch_input(input_path, day) {
+        Ok(Fetched::Cached(path)) => {
+            println!("Input of Day {} is already in '{}'", day, path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(Fetched::Downloaded(path)) => {
+            println!("Input of Day {} downloaded to '{}'", day, path.display());
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Could not fetch the input of Day {}. {}", day, error);
+            ExitCode::from(EXIT_WEBSITE_ERROR)
+        }
+    }
+}
+
+fn submit(
+    input_path: &Path,
+    day: u8,
+    part: u8,
+    submissions: &Path,
+    no_shape_check: bool,
+    website: &WebsiteArgs,
+) -> ExitCode {
+    let solver = match solvers::solve(day, part) {
+        Ok(solver) => solver,
+        Err(_) => {
+            eprintln!("Unsupported day {} and part {}", day, part);
+            return ExitCode::from(EXIT_UNSUPPORTED);
+        }
+    };
+
+    let mut submission_log = match SubmissionLog::load(submissions) {
+        Ok(submission_log) => submission_log,
+        Err(error) => {
+            eprintln!(
+                "Could not load submissions file '{}'. {}",
+                submissions.display(),
+                error
+            );
+            return ExitCode::from(EXIT_MISSING_INPUT);
+        }
+    };
+
+    let source = InputSource::File(client::input_file(input_path, day));
+    let puzzle_input = match load_file(&source) {
+        Some(puzzle_input) => puzzle_input,
+        None => return ExitCode::from(EXIT_MISSING_INPUT),
+    };
+
+    if !check_input_shape(day, &None, &source, &puzzle_input, no_shape_check) {
+        return ExitCode::from(EXIT_INVALID_INPUT);
+    }
+
+    let answer = match solver.run(&puzzle_input) {
+        Ok(answer) => answer,
+        Err(error) => {
+            eprintln!(
+                "A problem occured to solve the problem of Day {}, Part {}: {}",
+                day, part, error
+            );
+            return error_exit_code(error.kind);
+        }
+    };
+
+    match submission_log.previous(day, part, &answer) {
+        Some(Outcome::Right) => {
+            println!(
+                "Answer {} of Day {}, Part {} was already accepted",
+                answer, day, part
+            );
+            return ExitCode::SUCCESS;
+        }
+        Some(outcome) => {
+            eprintln!(
+                "Answer {} of Day {}, Part {} was already submitted and judged wrong: {}",
+                answer, day, part, outcome
+            );
+            return ExitCode::from(EXIT_VERIFICATION_MISMATCH);
+        }
+        None => {}
+    }
+
+    let outcome = match website.client().submit_answer(day, part, &answer) {
+        Ok(outcome) => outcome,
+        Err(error) => {
+            eprintln!(
+                "Could not submit the answer of Day {}, Part {}. {}",
+                day, part, error
+            );
+            return ExitCode::from(EXIT_WEBSITE_ERROR);
+        }
+    };
+
+    println!(
+        "Answer {} of Day {}, Part {} submitted: {}",
+        answer, day, part, outcome
+    );
+
+    if let Err(error) = submission_log.record(submissions, day, part, &outcome, &answer) {
+        eprintln!(
+            "Could not record the submission in '{}'. {}",
+            submissions.display(),
+            error
+        );
+    }
+
+    match outcome {
+        Outcome::Right | Outcome::AlreadySolved => ExitCode::SUCCESS,
+        Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow => {
+            ExitCode::from(EXIT_VERIFICATION_MISMATCH)
+        }
+        Outcome::RateLimited(_) | Outcome::Unknown => ExitCode::from(EXIT_WEBSITE_ERROR),
+    }
+}
+
+// Append the runs of the main inputs to the history, with the median time of the benchmark when
+// there is one.
+fn record_history(history_file: &Path, runs: &[SolutionRun]) {
+    let timestamp = history::now();
+    let revision = history::git_revision();
+
+    let entries: Vec<HistoryEntry> = runs
+        .iter()
+        .filter(|run| run.variant.is_none())
+        .map(|run| HistoryEntry {
+            timestamp,
+            revision: revision.clone(),
+            profile: history::build_profile().to_string(),
+            day: run.solver.day,
+            part: run.solver.part,
+            answer_hash: history::answer_hash(&run.result),
+            // Always the single timed run, even when benchmarking, so that every run of the
+            // history is compared like with like.
+            elapsed: run.time,
+        })
+        .collect();
+
+    if let Err(error) = History::append(history_file, &entries) {
+        eprintln!(
+            "Could not record the runs in history file '{}'. {}",
+            history_file.display(),
+            error
+        );
+    }
+}
+
+// Update the baseline file with the median times of the benchmarked solvers on the main inputs,
+// keeping the other solvers' entries.
+fn save_baseline(baseline_file: &Path, runs: &[SolutionRun]) -> Result<usize, String> {
+    let mut baseline = Baseline::load(baseline_file)?;
+    let mut saved = 0;
+
+    for run in runs
+        .iter()
+        .filter(|run| run.variant.is_none() && run.result.is_ok())
+    {
+        if let Some(statistics) = &run.statistics {
+            baseline.insert(run.solver.day, run.solver.part, statistics.median);
+            saved += 1;
+        }
+    }
+
+    baseline
+        .save(baseline_file)
+        .map_err(|error| error.to_string())?;
+    Ok(saved)
+}
+
+fn micros(time: Option<Duration>) -> String {
+    match time {
+        Some(time) => time.as_micros().to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn show_history(
+    history_file: &Path,
+    day: Option<u8>,
+    part: Option<u8>,
+    threshold: f64,
+) -> ExitCode {
+    let history = match History::load(history_file) {
+        Ok(history) => history,
+        Err(error) => {
+            eprintln!(
+                "Could not load history file '{}'. {}",
+                history_file.display(),
+                error
+            );
+            return ExitCode::from(EXIT_MISSING_INPUT);
+        }
+    };
+
+    let trends: Vec<_> = history
+        .trends(threshold)
+        .into_iter()
+        .filter(|trend| {
+            day.is_none_or(|day| trend.day == day) && part.is_none_or(|part| trend.part == part)
+        })
         .collect();
 
-    if let Some(puzzle_input) = load_file(day_input) {
-        match solve(cli.day, cli.part) {
-            Ok(solve_function) => {
-                chrono_start = Instant::now();
-                solution_result = solve_function(&puzzle_input);
-                chrono_stop = chrono_start.elapsed().as_micros();
-                total_time += chrono_stop;
-
-                match solution_result {
-                    Ok(solution) => println!(
-                        "Solution of Day {}, Part {}: {}, Time: {}μs",
-                        cli.day, cli.part, solution, chrono_stop
-                    ),
-                    Err(error) => println!(
-                        "A problem occured to solve the problem of Day {}, Part {}: {}, Time: {}μs",
-                        cli.day, cli.part, error, chrono_stop
-                    ),
+    if trends.is_empty() {
+        eprintln!(
+            "No runs recorded in history file '{}'",
+            history_file.display()
+        );
+        return ExitCode::from(EXIT_MISSING_INPUT);
+    }
+
+    println!(
+        "| Day | Part | {:<7} | {:>6} | {:>12} | {:>12} | {:>12} |",
+        "Profile", "Runs", "First (μs)", "Latest (μs)", "Best (μs)"
+    );
+    println!("|-----|------|---------|--------|--------------|--------------|--------------|");
+    for trend in &trends {
+        println!(
+            "| {:>3} | {:>4} | {:<7} | {:>6} | {:>12} | {:>12} | {:>12} |",
+            trend.day,
+            trend.part,
+            trend.profile,
+            trend.runs.len(),
+            micros(trend.first()),
+            micros(trend.latest()),
+            micros(trend.best())
+        );
+    }
+
+    let mut flags = Vec::new();
+
+    for trend in &trends {
+        if let Some(run) = trend.answer_change {
+            flags.push(format!(
+                "Day {}, Part {} ({}): answer changed on {} ({})",
+                trend.day,
+                trend.part,
+                trend.profile,
+                history::format_timestamp(run.timestamp),
+                run.revision.as_deref().unwrap_or("no revision")
+            ));
+        }
+        if let Some((run, median)) = trend.regression {
+            flags.push(format!(
+                "Day {}, Part {} ({}): time regressed on {} ({}), {}μs against a median of {}μs before",
+                trend.day,
+                trend.part,
+                trend.profile,
+                history::format_timestamp(run.timestamp),
+                run.revision.as_deref().unwrap_or("no revision"),
+                run.elapsed.as_micros(),
+                median.as_micros()
+            ));
+        }
+    }
+
+    if !flags.is_empty() {
+        println!("\n{}", flags.join("\n"));
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn new_day(src_dir: &Path, day: u8, title: &str) -> ExitCode {
+    match scaffold::new_day(src_dir, day, title) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Updated '{}'", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Could not generate Day {}. {}", day, error);
+            ExitCode::from(EXIT_SCAFFOLD_FAILED)
+        }
+    }
+}
+
+// Report every problem of each puzzle input, returning the number of invalid inputs.
+fn validate_inputs(day_jobs: &[DayJob]) -> usize {
+    let mut invalid_inputs = 0;
+
+    for job in day_jobs {
+        let day_label = day_label(job.solvers[0].day, &job.variant);
+        let problems = (job.solvers[0].validate)(&job.puzzle_input);
+
+        if problems.is_empty() {
+            println!("VALID   Input of {} '{}'", day_label, job.source);
+            continue;
+        }
+
+        invalid_inputs += 1;
+        println!(
+            "INVALID Input of {} '{}', {} problem(s):",
+            day_label,
+            job.source,
+            problems.len()
+        );
+        for problem in &problems {
+            println!("    {}", problem);
+        }
+        if let InputShape::LooksLike(other_day) =
+            solvers::input_shape(job.solvers[0].day, &job.puzzle_input)
+        {
+            println!("    Input looks like Day {}", other_day);
+        }
+    }
+
+    if invalid_inputs > 0 {
+        eprintln!("\n{} input(s) did not match their grammar", invalid_inputs);
+    }
+
+    invalid_inputs
+}
+
+// Run the examples of every solver and return how many did not give the expected answer.
+// Verdicts are listed in text mode, while mismatches are always reported on stderr.
+fn check_examples(solvers: &[&Solver], format: OutputFormat) -> usize {
+    let mut failures = 0;
+
+    for solver in solvers {
+        if solver.examples().next().is_none() && format == OutputFormat::Text {
+            println!(
+                "{:<7} Day {}, Part {}: no example",
+                Verdict::Missing,
+                solver.day,
+                solver.part
+            );
+        }
+
+        for (index, example) in solver.examples().enumerate() {
+            let result = solver.run(example.input);
+            let solution = match &result {
+                Ok(solution) => solution.to_string(),
+                Err(error) => format!("ERROR: {}", error),
+            };
+
+            match example.verify(&result) {
+                Verdict::Fail { expected } => {
+                    failures += 1;
+                    eprintln!(
+                        "FAIL    Example {} of Day {}, Part {}: {} (expected {})",
+                        index + 1,
+                        solver.day,
+                        solver.part,
+                        solution,
+                        expected
+                    );
+                }
+                verdict => {
+                    if format == OutputFormat::Text {
+                        println!(
+                            "{:<7} Example {} of Day {}, Part {}: {}",
+                            verdict,
+                            index + 1,
+                            solver.day,
+                            solver.part,
+                            solution
+                        );
+                    }
                 }
-                println!("\nTotal Time: {}μs", total_time);
             }
-            Err(_) => println!("Unsupported day {} and part {}", cli.day, cli.part),
         }
     }
+
+    if failures == 0 && format == OutputFormat::Text {
+        println!();
+    }
+
+    failures
+}
+
+fn print_check(runs: &[SolutionRun], failures: usize) {
+    println!();
+    for run in runs {
+        match &run.verdict {
+            Some(Verdict::Fail { expected }) => println!(
+                "FAIL    {}, Part {}: {} (expected {})",
+                day_label(run.solver.day, &run.variant),
+                run.solver.part,
+                run.solution(),
+                expected
+            ),
+            Some(verdict) => println!(
+                "{:<7} {}, Part {}: {}",
+                verdict,
+                day_label(run.solver.day, &run.variant),
+                run.solver.part,
+                run.solution()
+            ),
+            None => {}
+        }
+    }
+
+    if failures > 0 {
+        eprintln!("\n{} answer(s) did not match", failures);
+    }
+}
+
+fn print_comparison(runs: &[SolutionRun], slower: usize, max_regression: f64) {
+    println!();
+    for (run, comparison) in runs
+        .iter()
+        .filter_map(|run| run.comparison.as_ref().map(|comparison| (run, comparison)))
+    {
+        let median = run
+            .statistics
+            .as_ref()
+            .map(|statistics| statistics.median)
+            .unwrap_or_default();
+
+        match comparison {
+            Comparison::Within { baseline } | Comparison::Slower { baseline } => println!(
+                "{:<7} Day {}, Part {}: {} against {} ({:+.1}%)",
+                comparison,
+                run.solver.day,
+                run.solver.part,
+                bench::format_duration(median),
+                bench::format_duration(*baseline),
+                baseline::change_percent(*baseline, median)
+            ),
+            Comparison::Missing => println!(
+                "{:<7} Day {}, Part {}: {}",
+                comparison,
+                run.solver.day,
+                run.solver.part,
+                bench::format_duration(median)
+            ),
+        }
+    }
+
+    if slower > 0 {
+        eprintln!(
+            "\n{} solver(s) slower than their baseline by more than {}%",
+            slower, max_regression
+        );
+    }
+}
+
+fn print_summary(runs: &[SolutionRun]) {
+    let solutions: Vec<String> = runs.iter().map(SolutionRun::solution).collect();
+    let solution_width = solutions
+        .iter()
+        .map(|solution| solution.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Solution".len());
+    let title_width = runs
+        .iter()
+        .map(|run| run.title().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Title".len());
+
+    println!();
+    println!(
+        "| Day | {:<title_width$} | Part | {:<solution_width$} | {:>12} | {:>12} |",
+        "Title", "Solution", "Parse (μs)", "Time (μs)",
+    );
+    println!(
+        "|-----|-{}-|------|-{}-|--------------|--------------|",
+        "-".repeat(title_width),
+        "-".repeat(solution_width)
+    );
+    for (run, solution) in runs.iter().zip(solutions.iter()) {
+        println!(
+            "| {:>3} | {:<title_width$} | {:>4} | {:<solution_width$} | {:>12} | {:>12} |",
+            run.solver.day,
+            run.title(),
+            run.solver.part,
+            solution,
+            run.parse_time.as_micros(),
+            run.time.as_micros(),
+        );
+    }
+}
+
+fn print_bench_summary(runs: &[SolutionRun]) {
+    let title_width = runs
+        .iter()
+        .map(|run| run.title().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Title".len());
+
+    println!();
+    println!(
+        "| Day | {:<title_width$} | Part | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} |",
+        "Title", "Iterations", "Min", "Mean", "Median", "p95", "Std dev",
+    );
+    println!(
+        "|-----|-{}-|------|------------|------------|------------|------------|------------|------------|",
+        "-".repeat(title_width)
+    );
+    for run in runs {
+        if let Some(statistics) = &run.statistics {
+            println!(
+                "| {:>3} | {:<title_width$} | {:>4} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} |",
+                run.solver.day,
+                run.title(),
+                run.solver.part,
+                statistics.iterations,
+                bench::format_duration(statistics.min),
+                bench::format_duration(statistics.mean),
+                bench::format_duration(statistics.median),
+                bench::format_duration(statistics.p95),
+                bench::format_duration(statistics.stddev),
+            );
+        }
+    }
+}
+
+fn print_allocation_summary(runs: &[SolutionRun]) {
+    let title_width = runs
+        .iter()
+        .map(|run| run.title().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Title".len());
+
+    println!();
+    println!(
+        "| Day | {:<title_width$} | Part | {:>10} | {:>12} | {:>12} | {:>12} | {:>10} | {:>12} | {:>12} | {:>12} |",
+        "Title",
+        "Parse (μs)",
+        "Parse allocs",
+        "Parse (B)",
+        "Parse peak",
+        "Time (μs)",
+        "Allocs",
+        "Allocated",
+        "Peak",
+    );
+    println!(
+        "|-----|-{}-|------|------------|--------------|--------------|--------------|------------|--------------|--------------|--------------|",
+        "-".repeat(title_width)
+    );
+    for run in runs {
+        let parse_allocations = run.parse_allocations.unwrap_or_default();
+        let allocations = run.allocations.unwrap_or_default();
+        println!(
+            "| {:>3} | {:<title_width$} | {:>4} | {:>10} | {:>12} | {:>12} | {:>12} | {:>10} | {:>12} | {:>12} | {:>12} |",
+            run.solver.day,
+            run.title(),
+            run.solver.part,
+            run.parse_time.as_micros(),
+            parse_allocations.allocations,
+            parse_allocations.bytes_allocated,
+            parse_allocations.peak_live_bytes,
+            run.time.as_micros(),
+            allocations.allocations,
+            allocations.bytes_allocated,
+            allocations.peak_live_bytes,
+        );
+    }
 }
@@ -0,0 +1,95 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use crate::error::SolveError;
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+// Panics caught by `catch_panic` only have their location recorded, the others are reported by
+// the previous hook as usual.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) {
+                let location = info.location().map(|location| location.to_string());
+                LOCATION.with(|cell| *cell.borrow_mut() = location);
+            } else {
+                previous_hook(info);
+            }
+        }));
+    });
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => message,
+            None => "Box<dyn Any>",
+        },
+    }
+}
+
+// Run `function`, turning a panic into an error with the panic message and its location.
+pub fn catch_panic<T, F: FnOnce() -> Result<T, SolveError>>(function: F) -> Result<T, SolveError> {
+    install_hook();
+
+    let was_catching = CATCHING.with(|cell| cell.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(function));
+    CATCHING.with(|cell| cell.set(was_catching));
+
+    match result {
+        Ok(result) => result,
+        Err(payload) => {
+            let message = payload_message(payload.as_ref());
+
+            Err(match LOCATION.with(|cell| cell.borrow_mut().take()) {
+                Some(location) => SolveError::panic(format!("{} at {}", message, location)),
+                None => SolveError::panic(message),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{ErrorKind, SolveError};
+    use crate::panic::catch_panic;
+
+    #[test]
+    fn test_catch_panic() {
+        let result: Result<(), SolveError> = catch_panic(|| {
+            let fields: Vec<&str> = "Game 1 3 blue".split(':').collect();
+            assert!(fields.len() > 1, "No ':' in {:?}", fields[0]);
+            Ok(())
+        });
+
+        match result {
+            Err(error) => {
+                assert_eq!(error.kind, ErrorKind::Panic);
+                assert!(error
+                    .message
+                    .starts_with(&format!("No ':' in \"Game 1 3 blue\" at {}:", file!())));
+            }
+            Ok(()) => panic!("The panic wasn't caught"),
+        }
+    }
+
+    #[test]
+    fn test_catch_panic_without_panic() {
+        assert_eq!(catch_panic(|| Ok(142)), Ok(142));
+        assert_eq!(
+            catch_panic::<(), _>(|| Err(SolveError::parse("x"))),
+            Err(SolveError::parse("x"))
+        );
+    }
+}
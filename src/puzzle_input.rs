@@ -0,0 +1,221 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+// Where a puzzle input is read from: a file, or the standard input when given "-".
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl From<&str> for InputSource {
+    fn from(argument: &str) -> Self {
+        match argument {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+impl InputSource {
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut puzzle_input = String::new();
+                io::stdin().read_to_string(&mut puzzle_input)?;
+                Ok(puzzle_input)
+            }
+        }
+    }
+}
+
+// Whether the input is already normalised, which is the case of downloaded inputs.
+fn is_normalised(input: &str) -> bool {
+    !input.starts_with('\u{feff}')
+        && (input.is_empty() || input.ends_with('\n'))
+        && input
+            .split_terminator('\n')
+            .all(|line| line.trim_end().len() == line.len())
+        && input
+            .split_terminator('\n')
+            .next_back()
+            .is_none_or(|line| !line.is_empty())
+}
+
+// Puzzle input as every solver expects it: without a UTF-8 BOM, with '\n' line endings, no
+// trailing whitespace on the lines and no trailing blank lines, but a final new line unless the
+// input is empty. Inputs saved on Windows or edited by hand give the same answers as downloaded
+// ones. An input that is already normalised is borrowed rather than copied.
+pub fn normalise(input: &str) -> Cow<'_, str> {
+    if is_normalised(input) {
+        return Cow::Borrowed(input);
+    }
+
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut normalised = String::with_capacity(input.len() + 1);
+
+    for line in input.lines() {
+        normalised.push_str(line.trim_end());
+        normalised.push('\n');
+    }
+
+    normalised.truncate(normalised.trim_end().len());
+    if !normalised.is_empty() {
+        normalised.push('\n');
+    }
+
+    Cow::Owned(normalised)
+}
+
+// Named puzzle input of a day, such as the input of another account, read from
+// "<input_dir>/dayN/<name>.txt" with the expected answers in "<name>.answers" next to it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputVariant {
+    pub name: String,
+    pub input_file: PathBuf,
+    pub answers_file: Option<PathBuf>,
+}
+
+// Variants of the puzzle input of a day, sorted by name. A day without a folder has none.
+pub fn input_variants(input_dir: &Path, day: u8) -> io::Result<Vec<InputVariant>> {
+    let day_dir = input_dir.join(format!("day{}", day));
+
+    let entries = match fs::read_dir(&day_dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error),
+    };
+
+    let mut variants = Vec::new();
+
+    for entry in entries {
+        let input_file = entry?.path();
+
+        if !input_file.is_file()
+            || input_file
+                .extension()
+                .is_none_or(|extension| extension != "txt")
+        {
+            continue;
+        }
+
+        let answers_file = input_file.with_extension("answers");
+
+        variants.push(InputVariant {
+            name: input_file
+                .file_stem()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            answers_file: Some(answers_file).filter(|answers_file| answers_file.is_file()),
+            input_file,
+        });
+    }
+
+    variants.sort_by(|left, right| left.name.cmp(&right.name));
+    Ok(variants)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::puzzle_input::{input_variants, normalise, InputSource, InputVariant};
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn test_input_source_from_argument() {
+        assert_eq!(InputSource::from("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from("inputs/day3.txt"),
+            InputSource::File(PathBuf::from("inputs/day3.txt"))
+        );
+    }
+
+    #[test]
+    fn test_input_source_display() {
+        assert_eq!(InputSource::Stdin.to_string(), "<stdin>");
+        assert_eq!(
+            InputSource::from("inputs/day3.txt").to_string(),
+            "inputs/day3.txt"
+        );
+    }
+
+    #[test]
+    fn test_read_file() {
+        let input_path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "src", "input", "day2.txt"]
+            .iter()
+            .collect();
+
+        assert!(InputSource::File(input_path)
+            .read()
+            .unwrap()
+            .starts_with("Game 1:"));
+        assert!(InputSource::from("does/not/exist.txt").read().is_err());
+    }
+
+    #[test]
+    fn test_normalise() {
+        let expected = "467..114..\n...*......\n";
+
+        assert!(matches!(normalise(expected), Cow::Borrowed(_)));
+        assert!(matches!(
+            normalise("467..114..\n\n...*......\n"),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(normalise(""), Cow::Borrowed(_)));
+        assert_eq!(normalise("\n"), "");
+        assert_eq!(normalise("467..114..\n...*......"), expected);
+        assert_eq!(normalise("\u{feff}467..114..\n...*......\n"), expected);
+        assert_eq!(normalise("467..114..\r\n...*......\r\n"), expected);
+        assert_eq!(normalise("467..114..  \n...*......\t\n"), expected);
+        assert_eq!(normalise("467..114..\n...*......\n\n  \n\r\n"), expected);
+        assert_eq!(
+            normalise("\u{feff}467..114.. \r\n...*......\r\n\r\n"),
+            expected
+        );
+        assert_eq!(normalise("\u{feff}\r\n\n"), "");
+        assert_eq!(normalise(""), "");
+    }
+
+    #[test]
+    fn test_input_variants() {
+        let input_dir = TempDir::new("variants");
+        let day_dir = input_dir.join("day4");
+        fs::create_dir_all(&day_dir).unwrap();
+        for file in ["bob.txt", "alice.txt", "alice.answers", "notes.md"] {
+            fs::write(day_dir.join(file), "").unwrap();
+        }
+
+        assert_eq!(
+            input_variants(&input_dir, 4).unwrap(),
+            vec![
+                InputVariant {
+                    name: "alice".to_string(),
+                    input_file: day_dir.join("alice.txt"),
+                    answers_file: Some(day_dir.join("alice.answers")),
+                },
+                InputVariant {
+                    name: "bob".to_string(),
+                    input_file: day_dir.join("bob.txt"),
+                    answers_file: None,
+                },
+            ]
+        );
+        assert_eq!(input_variants(&input_dir, 3).unwrap(), vec![]);
+    }
+}
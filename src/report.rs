@@ -0,0 +1,432 @@
+use serde::Serialize;
+
+use std::time::Duration;
+
+use crate::allocation::AllocationStats;
+use crate::baseline::{self, Comparison};
+use crate::bench::Statistics;
+
+// One solver run, as emitted by the machine-readable output formats.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct RunRecord {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    // Time to parse the input (shared by both parts of a day) and time to solve the part.
+    pub parse_ns: u64,
+    pub elapsed_ns: u64,
+    pub input_file: String,
+    // Name of the input variant, for the inputs read from a day folder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bench: Option<BenchRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comparison: Option<ComparisonRecord>,
+    // Allocations made while parsing the input and while solving the part.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_allocations: Option<AllocationRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<AllocationRecord>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct BenchRecord {
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl From<&Statistics> for BenchRecord {
+    fn from(statistics: &Statistics) -> Self {
+        Self {
+            iterations: statistics.iterations,
+            min_ns: statistics.min.as_nanos() as u64,
+            mean_ns: statistics.mean.as_nanos() as u64,
+            median_ns: statistics.median.as_nanos() as u64,
+            p95_ns: statistics.p95.as_nanos() as u64,
+            stddev_ns: statistics.stddev.as_nanos() as u64,
+        }
+    }
+}
+
+// Median time of a benchmarked solver against its baseline (--compare). The baseline and the
+// change are missing for a solver without a baseline.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ComparisonRecord {
+    pub verdict: String,
+    pub baseline_ns: Option<u64>,
+    pub change_percent: Option<f64>,
+}
+
+impl ComparisonRecord {
+    pub fn new(comparison: &Comparison, median: Duration) -> Self {
+        let baseline = match comparison {
+            Comparison::Within { baseline } | Comparison::Slower { baseline } => Some(*baseline),
+            Comparison::Missing => None,
+        };
+
+        Self {
+            verdict: comparison.to_string(),
+            baseline_ns: baseline.map(|baseline| baseline.as_nanos() as u64),
+            change_percent: baseline.map(|baseline| baseline::change_percent(baseline, median)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct AllocationRecord {
+    pub allocations: usize,
+    pub bytes_allocated: usize,
+    pub peak_live_bytes: usize,
+}
+
+impl From<&AllocationStats> for AllocationRecord {
+    fn from(stats: &AllocationStats) -> Self {
+        Self {
+            allocations: stats.allocations,
+            bytes_allocated: stats.bytes_allocated,
+            peak_live_bytes: stats.peak_live_bytes,
+        }
+    }
+}
+
+pub fn to_json(records: &[RunRecord]) -> String {
+    match serde_json::to_string_pretty(records) {
+        Ok(json) => json,
+        Err(error) => unreachable!("Run records are always serializable: {}", error),
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn allocation_fields(allocations: &Option<AllocationRecord>) -> Vec<String> {
+    match allocations {
+        Some(allocations) => [
+            allocations.allocations,
+            allocations.bytes_allocated,
+            allocations.peak_live_bytes,
+        ]
+        .map(|value| value.to_string())
+        .to_vec(),
+        None => vec![String::new(); 3],
+    }
+}
+
+// The variant, check, bench, comparison and allocation columns are only present when at least one
+// record has them.
+pub fn to_csv(records: &[RunRecord]) -> String {
+    let with_variant = records.iter().any(|record| record.variant.is_some());
+    let with_check = records.iter().any(|record| record.check.is_some());
+    let with_bench = records.iter().any(|record| record.bench.is_some());
+    let with_comparison = records.iter().any(|record| record.comparison.is_some());
+    let with_allocations = records
+        .iter()
+        .any(|record| record.parse_allocations.is_some() || record.allocations.is_some());
+
+    let mut header = vec![
+        "day",
+        "part",
+        "answer",
+        "error",
+        "parse_ns",
+        "elapsed_ns",
+        "input_file",
+    ];
+    if with_variant {
+        header.push("variant");
+    }
+    if with_check {
+        header.push("check");
+    }
+    if with_bench {
+        header.extend([
+            "iterations",
+            "min_ns",
+            "mean_ns",
+            "median_ns",
+            "p95_ns",
+            "stddev_ns",
+        ]);
+    }
+    if with_comparison {
+        header.extend(["comparison", "baseline_ns", "change_percent"]);
+    }
+    if with_allocations {
+        header.extend([
+            "parse_allocations",
+            "parse_bytes_allocated",
+            "parse_peak_live_bytes",
+            "allocations",
+            "bytes_allocated",
+            "peak_live_bytes",
+        ]);
+    }
+
+    let mut csv = header.join(",");
+    csv.push('\n');
+
+    for record in records {
+        let mut fields = vec![
+            record.day.to_string(),
+            record.part.to_string(),
+            csv_field(record.answer.as_deref().unwrap_or_default()),
+            csv_field(record.error.as_deref().unwrap_or_default()),
+            record.parse_ns.to_string(),
+            record.elapsed_ns.to_string(),
+            csv_field(&record.input_file),
+        ];
+        if with_variant {
+            fields.push(csv_field(record.variant.as_deref().unwrap_or_default()));
+        }
+        if with_check {
+            fields.push(record.check.clone().unwrap_or_default());
+        }
+        if with_bench {
+            match &record.bench {
+                Some(bench) => fields.extend(
+                    [
+                        bench.iterations as u64,
+                        bench.min_ns,
+                        bench.mean_ns,
+                        bench.median_ns,
+                        bench.p95_ns,
+                        bench.stddev_ns,
+                    ]
+                    .map(|value| value.to_string()),
+                ),
+                None => fields.extend(vec![String::new(); 6]),
+            }
+        }
+        if with_comparison {
+            match &record.comparison {
+                Some(comparison) => fields.extend([
+                    comparison.verdict.clone(),
+                    comparison
+                        .baseline_ns
+                        .map(|baseline_ns| baseline_ns.to_string())
+                        .unwrap_or_default(),
+                    comparison
+                        .change_percent
+                        .map(|change_percent| format!("{:.1}", change_percent))
+                        .unwrap_or_default(),
+                ]),
+                None => fields.extend(vec![String::new(); 3]),
+            }
+        }
+        if with_allocations {
+            fields.extend(allocation_fields(&record.parse_allocations));
+            fields.extend(allocation_fields(&record.allocations));
+        }
+
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::baseline::Comparison;
+    use crate::report::{
+        to_csv, to_json, AllocationRecord, BenchRecord, ComparisonRecord, RunRecord,
+    };
+
+    fn test_records() -> Vec<RunRecord> {
+        vec![
+            RunRecord {
+                day: 1,
+                part: 1,
+                answer: Some("142".to_string()),
+                error: None,
+                parse_ns: 700,
+                elapsed_ns: 1500,
+                input_file: "input/day1.txt".to_string(),
+                variant: None,
+                check: None,
+                bench: None,
+                comparison: None,
+                parse_allocations: None,
+                allocations: None,
+            },
+            RunRecord {
+                day: 3,
+                part: 2,
+                answer: None,
+                error: Some("Couldn't parse \"1,2\"".to_string()),
+                parse_ns: 300,
+                elapsed_ns: 20,
+                input_file: "input/day3.txt".to_string(),
+                variant: None,
+                check: None,
+                bench: None,
+                comparison: None,
+                parse_allocations: None,
+                allocations: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&test_records())).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {
+                    "day": 1,
+                    "part": 1,
+                    "answer": "142",
+                    "error": null,
+                    "parse_ns": 700,
+                    "elapsed_ns": 1500,
+                    "input_file": "input/day1.txt"
+                },
+                {
+                    "day": 3,
+                    "part": 2,
+                    "answer": null,
+                    "error": "Couldn't parse \"1,2\"",
+                    "parse_ns": 300,
+                    "elapsed_ns": 20,
+                    "input_file": "input/day3.txt"
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&test_records()),
+            "day,part,answer,error,parse_ns,elapsed_ns,input_file
+1,1,142,,700,1500,input/day1.txt
+3,2,,\"Couldn't parse \"\"1,2\"\"\",300,20,input/day3.txt
+"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_with_check_and_bench() {
+        let mut records = test_records();
+        records[0].check = Some("PASS".to_string());
+        records[0].bench = Some(BenchRecord {
+            iterations: 10,
+            min_ns: 1,
+            mean_ns: 2,
+            median_ns: 3,
+            p95_ns: 4,
+            stddev_ns: 5,
+        });
+
+        assert_eq!(
+            to_csv(&records),
+            "day,part,answer,error,parse_ns,elapsed_ns,input_file,check,iterations,min_ns,mean_ns,median_ns,p95_ns,stddev_ns
+1,1,142,,700,1500,input/day1.txt,PASS,10,1,2,3,4,5
+3,2,,\"Couldn't parse \"\"1,2\"\"\",300,20,input/day3.txt,,,,,,,
+"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_with_allocations() {
+        let mut records = test_records();
+        records[1].parse_allocations = Some(AllocationRecord {
+            allocations: 12,
+            bytes_allocated: 4096,
+            peak_live_bytes: 2048,
+        });
+        records[1].allocations = Some(AllocationRecord {
+            allocations: 0,
+            bytes_allocated: 0,
+            peak_live_bytes: 0,
+        });
+
+        assert_eq!(
+            to_csv(&records),
+            "day,part,answer,error,parse_ns,elapsed_ns,input_file,parse_allocations,parse_bytes_allocated,parse_peak_live_bytes,allocations,bytes_allocated,peak_live_bytes
+1,1,142,,700,1500,input/day1.txt,,,,,,
+3,2,,\"Couldn't parse \"\"1,2\"\"\",300,20,input/day3.txt,12,4096,2048,0,0,0
+"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_with_variants() {
+        let mut records = test_records();
+        records[1].input_file = "input/day3/alice.txt".to_string();
+        records[1].variant = Some("alice".to_string());
+
+        assert_eq!(
+            to_csv(&records),
+            "day,part,answer,error,parse_ns,elapsed_ns,input_file,variant
+1,1,142,,700,1500,input/day1.txt,
+3,2,,\"Couldn't parse \"\"1,2\"\"\",300,20,input/day3/alice.txt,alice
+"
+        );
+    }
+
+    #[test]
+    fn test_comparison_record() {
+        assert_eq!(
+            ComparisonRecord::new(
+                &Comparison::Slower {
+                    baseline: Duration::from_nanos(200)
+                },
+                Duration::from_nanos(250)
+            ),
+            ComparisonRecord {
+                verdict: "SLOWER".to_string(),
+                baseline_ns: Some(200),
+                change_percent: Some(25.0),
+            }
+        );
+        assert_eq!(
+            ComparisonRecord::new(&Comparison::Missing, Duration::from_nanos(250)),
+            ComparisonRecord {
+                verdict: "MISSING".to_string(),
+                baseline_ns: None,
+                change_percent: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_csv_with_comparison() {
+        let mut records = test_records();
+        records[0].comparison = Some(ComparisonRecord {
+            verdict: "OK".to_string(),
+            baseline_ns: Some(1200),
+            change_percent: Some(25.0),
+        });
+        records[1].comparison = Some(ComparisonRecord {
+            verdict: "MISSING".to_string(),
+            baseline_ns: None,
+            change_percent: None,
+        });
+
+        assert_eq!(
+            to_csv(&records),
+            "day,part,answer,error,parse_ns,elapsed_ns,input_file,comparison,baseline_ns,change_percent
+1,1,142,,700,1500,input/day1.txt,OK,1200,25.0
+3,2,,\"Couldn't parse \"\"1,2\"\"\",300,20,input/day3.txt,MISSING,,
+"
+        );
+    }
+}
@@ -0,0 +1,395 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+const DAY_TEMPLATE: &str = r#"/*
+--- Day {day}: {title} ---
+
+https://adventofcode.com/2023/day/{day}
+*/
+
+use crate::answer::Answer;
+use crate::error::SolveError;
+use crate::example::Example;
+use crate::solvers::FingerprintFn;
+use crate::validation::validate_lines;
+
+pub static EXAMPLES: &[Example] = &[];
+
+pub struct Puzzle {
+    pub lines: Vec<String>,
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, SolveError> {
+    Ok(Puzzle {
+        lines: input.lines().map(String::from).collect(),
+    })
+}
+
+// Only rejects an empty input and empty lines until the input grammar is written.
+pub fn validate(input: &str) -> Vec<SolveError> {
+    validate_lines(input, |_line| Ok(()))
+}
+
+// No fingerprint until the shape of the input is known: the inputs given to this day aren't
+// checked, and the inputs of the other days are never taken for it.
+pub static FINGERPRINT: Option<FingerprintFn> = None;
+
+pub fn part1(_puzzle: &Puzzle) -> Result<Answer, SolveError> {
+    Err(SolveError::unsupported("Part 1 of day {day} is not solved yet"))
+}
+
+pub fn part2(_puzzle: &Puzzle) -> Result<Answer, SolveError> {
+    Err(SolveError::unsupported("Part 2 of day {day} is not solved yet"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::day{day}::{parse, part1, part2};
+
+    static TEST_INPUT: &str = "";
+
+    #[test]
+    #[ignore = "Part 1 is not solved yet"]
+    fn test_part1() {
+        assert_eq!(part1(&parse(TEST_INPUT).unwrap()), Ok(Answer::from(0)));
+    }
+
+    #[test]
+    #[ignore = "Part 2 is not solved yet"]
+    fn test_part2() {
+        assert_eq!(part2(&parse(TEST_INPUT).unwrap()), Ok(Answer::from(0)));
+    }
+}
+"#;
+
+pub fn day_module(day: u8, title: &str) -> String {
+    DAY_TEMPLATE
+        .replace("{day}", &day.to_string())
+        .replace("{title}", title)
+}
+
+fn day_number(line: &str, pattern: &Regex) -> Option<u8> {
+    pattern
+        .captures(line)
+        .and_then(|captures| captures[1].parse::<u8>().ok())
+}
+
+// Index where a new entry of `day` goes among lines numbered by `pattern`, to keep them sorted by day.
+fn insertion_index(lines: &[&str], pattern: &Regex, day: u8) -> Option<usize> {
+    let mut first_index = None;
+    let mut insertion_index = None;
+
+    for (index, line) in lines.iter().enumerate() {
+        if let Some(line_day) = day_number(line, pattern) {
+            first_index = first_index.or(Some(index));
+            if line_day < day {
+                insertion_index = Some(index + 1);
+            }
+        }
+    }
+
+    insertion_index.or(first_index)
+}
+
+fn join_lines(lines: &[&str]) -> String {
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
+
+// Declare the module of `day` in lib.rs.
+pub fn register_module(lib: &str, day: u8) -> Result<String, String> {
+    let pattern = Regex::new(r"^pub mod day(\d+);$").unwrap();
+    let mut lines: Vec<&str> = lib.lines().collect();
+
+    if lines
+        .iter()
+        .any(|line| day_number(line, &pattern) == Some(day))
+    {
+        return Err(format!("Module day{} is already declared in lib.rs", day));
+    }
+
+    let declaration = format!("pub mod day{};", day);
+    let index = insertion_index(&lines, &pattern, day).unwrap_or(lines.len());
+    lines.insert(index, &declaration);
+
+    Ok(join_lines(&lines))
+}
+
+// Import the module of `day` in solvers.rs and add its solvers to the registry.
+pub fn register_solvers(solvers: &str, day: u8, title: &str) -> Result<String, String> {
+    let pattern = Regex::new(r"^\s*solver!\((\d+),").unwrap();
+    let mut lines: Vec<&str> = solvers.lines().collect();
+
+    if lines
+        .iter()
+        .any(|line| day_number(line, &pattern) == Some(day))
+    {
+        return Err(format!("Day {} is already registered in solvers.rs", day));
+    }
+
+    let index = match insertion_index(&lines, &pattern, day) {
+        Some(index) => index,
+        None => return Err("Couldn't find the solver registry in solvers.rs".to_string()),
+    };
+    let indentation = match lines.iter().find(|line| pattern.is_match(line)) {
+        Some(line) => &line[..line.len() - line.trim_start().len()],
+        None => "    ",
+    };
+    let entries: Vec<String> = [1, 2]
+        .iter()
+        .map(|part| {
+            format!(
+                "{}solver!({}, {}, {:?}, day{}::part{}),",
+                indentation, day, part, title, day, part
+            )
+        })
+        .collect();
+
+    for (offset, entry) in entries.iter().enumerate() {
+        lines.insert(index + offset, entry);
+    }
+
+    import_module(&join_lines(&lines), day)
+}
+
+// Add `day` to the `use crate::{day1, ...};` import, formatted as rustfmt would.
+fn import_module(solvers: &str, day: u8) -> Result<String, String> {
+    let pattern = Regex::new(r"use crate::\{\s*(day\d+(?:,\s*day\d+)*),?\s*\};").unwrap();
+
+    let (range, mut days) = match pattern.captures(solvers) {
+        Some(captures) => (
+            captures.get(0).unwrap().range(),
+            captures[1]
+                .split(',')
+                .filter_map(|module| module.trim().trim_start_matches("day").parse::<u8>().ok())
+                .collect::<Vec<u8>>(),
+        ),
+        None => return Err("Couldn't find the import of the day modules in solvers.rs".to_string()),
+    };
+
+    days.push(day);
+    days.sort_by_key(|day| day.to_string());
+    let modules: Vec<String> = days.iter().map(|day| format!("day{}", day)).collect();
+
+    let single_line = format!("use crate::{{{}}};", modules.join(", "));
+    let import = if single_line.len() <= 100 {
+        single_line
+    } else {
+        let mut import = String::from("use crate::{\n");
+        let mut line = String::new();
+        for module in &modules {
+            if !line.is_empty() && 4 + line.len() + module.len() + 1 > 100 {
+                import.push_str(&format!("    {}\n", line.trim_end()));
+                line.clear();
+            }
+            line.push_str(&format!("{}, ", module));
+        }
+        import.push_str(&format!(
+            "    {},\n}};",
+            line.trim_end().trim_end_matches(',')
+        ));
+        import
+    };
+
+    Ok(format!(
+        "{}{}{}",
+        &solvers[..range.start],
+        import,
+        &solvers[range.end..]
+    ))
+}
+
+// Generate the module of `day` in `src_dir` and register it in lib.rs and solvers.rs.
+// Nothing is written if the day isn't between 1 and 25, if the module file already exists or if
+// the day is already registered.
+pub fn new_day(src_dir: &Path, day: u8, title: &str) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {} is not between 1 and 25", day));
+    }
+
+    let module_file = src_dir.join(format!("day{}.rs", day));
+    let lib_file = src_dir.join("lib.rs");
+    let solvers_file = src_dir.join("solvers.rs");
+
+    if module_file.exists() {
+        return Err(format!("'{}' already exists", module_file.display()));
+    }
+
+    let read = |path: &Path| match fs::read_to_string(path) {
+        Ok(content) => Ok(content),
+        Err(error) => Err(format!("Could not read '{}'. {}", path.display(), error)),
+    };
+    let lib = register_module(&read(&lib_file)?, day)?;
+    let solvers = register_solvers(&read(&solvers_file)?, day, title)?;
+
+    let write_error = |path: &Path, error: std::io::Error| {
+        format!("Could not write '{}'. {}", path.display(), error)
+    };
+
+    match OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&module_file)
+    {
+        Ok(mut file) => match file.write_all(day_module(day, title).as_bytes()) {
+            Ok(()) => {}
+            Err(error) => return Err(write_error(&module_file, error)),
+        },
+        Err(error) => return Err(write_error(&module_file, error)),
+    }
+    for (path, content) in [(&lib_file, lib), (&solvers_file, solvers)] {
+        if let Err(error) = fs::write(path, content) {
+            return Err(write_error(path, error));
+        }
+    }
+
+    Ok(vec![module_file, lib_file, solvers_file])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::scaffold::{day_module, new_day, register_module, register_solvers};
+    use crate::temp_dir::TempDir;
+
+    static LIB: &str = "pub mod answer;
+pub mod day1;
+pub mod day2;
+pub mod day4;
+pub mod error;
+";
+
+    static SOLVERS: &str = "use crate::error::SolveError;
+use crate::{day1, day2, day4};
+
+pub static SOLVERS: &[Solver] = &[
+    solver!(1, 1, \"Trebuchet?!\", day1::part1),
+    solver!(1, 2, \"Trebuchet?!\", day1::part2),
+    solver!(2, 1, \"Cube Conundrum\", day2::part1),
+    solver!(2, 2, \"Cube Conundrum\", day2::part2),
+    solver!(4, 1, \"Scratchcards\", day4::part1),
+    solver!(4, 2, \"Scratchcards\", day4::part2),
+];
+";
+
+    #[test]
+    fn test_day_module() {
+        let module = day_module(5, "If You Give A Seed A Fertilizer");
+
+        assert!(module.starts_with("/*\n--- Day 5: If You Give A Seed A Fertilizer ---\n"));
+        assert!(module.contains("use crate::day5::{parse, part1, part2};"));
+        assert!(!module.contains("{day}"));
+    }
+
+    #[test]
+    fn test_register_module() {
+        assert_eq!(
+            register_module(LIB, 3),
+            Ok("pub mod answer;
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod error;
+"
+            .to_string())
+        );
+        assert!(register_module(LIB, 12)
+            .unwrap()
+            .contains("pub mod day4;\npub mod day12;\npub mod error;"));
+        assert_eq!(
+            register_module(LIB, 2),
+            Err("Module day2 is already declared in lib.rs".to_string())
+        );
+    }
+
+    #[test]
+    fn test_register_solvers() {
+        assert_eq!(
+            register_solvers(SOLVERS, 3, "Gear Ratios"),
+            Ok("use crate::error::SolveError;
+use crate::{day1, day2, day3, day4};
+
+pub static SOLVERS: &[Solver] = &[
+    solver!(1, 1, \"Trebuchet?!\", day1::part1),
+    solver!(1, 2, \"Trebuchet?!\", day1::part2),
+    solver!(2, 1, \"Cube Conundrum\", day2::part1),
+    solver!(2, 2, \"Cube Conundrum\", day2::part2),
+    solver!(3, 1, \"Gear Ratios\", day3::part1),
+    solver!(3, 2, \"Gear Ratios\", day3::part2),
+    solver!(4, 1, \"Scratchcards\", day4::part1),
+    solver!(4, 2, \"Scratchcards\", day4::part2),
+];
+"
+            .to_string())
+        );
+        assert!(register_solvers(SOLVERS, 10, "Pipe \"Maze\"")
+            .unwrap()
+            .contains("use crate::{day1, day10, day2, day4};"));
+        assert!(register_solvers(SOLVERS, 10, "Pipe \"Maze\"")
+            .unwrap()
+            .contains("    solver!(10, 2, \"Pipe \\\"Maze\\\"\", day10::part2),\n];"));
+        assert_eq!(
+            register_solvers(SOLVERS, 4, "Scratchcards"),
+            Err("Day 4 is already registered in solvers.rs".to_string())
+        );
+    }
+
+    #[test]
+    fn test_register_solvers_with_long_import() {
+        let solvers = SOLVERS.replace(
+            "use crate::{day1, day2, day4};",
+            "use crate::{\n    day1, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day2, day20,\n    day4,\n};",
+        );
+
+        assert!(register_solvers(&solvers, 21, "Step Counter")
+            .unwrap()
+            .contains("use crate::{\n    day1, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day2, day20, day21,\n    day4,\n};"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let src_dir = TempDir::new("scaffold");
+        fs::write(src_dir.join("lib.rs"), LIB).unwrap();
+        fs::write(src_dir.join("solvers.rs"), SOLVERS).unwrap();
+
+        assert_eq!(new_day(&src_dir, 5, "Seeds").unwrap().len(), 3);
+        assert_eq!(
+            fs::read_to_string(src_dir.join("day5.rs")).unwrap(),
+            day_module(5, "Seeds")
+        );
+        assert!(fs::read_to_string(src_dir.join("lib.rs"))
+            .unwrap()
+            .contains("pub mod day5;"));
+
+        // The generated module is never overwritten.
+        fs::write(src_dir.join("day5.rs"), "// Solved").unwrap();
+        assert!(new_day(&src_dir, 5, "Seeds").is_err());
+        assert_eq!(
+            fs::read_to_string(src_dir.join("day5.rs")).unwrap(),
+            "// Solved"
+        );
+
+        // Nothing is written when the day is already registered.
+        assert!(new_day(&src_dir, 4, "Scratchcards").is_err());
+        assert!(!src_dir.join("day4.rs").exists());
+
+        // Nothing is written for a day outside of the calendar.
+        for day in [0, 26, 200] {
+            assert_eq!(
+                new_day(&src_dir, day, "Out of range"),
+                Err(format!("Day {} is not between 1 and 25", day))
+            );
+            assert!(!src_dir.join(format!("day{}.rs", day)).exists());
+            assert!(!fs::read_to_string(src_dir.join("lib.rs"))
+                .unwrap()
+                .contains(&format!("pub mod day{};", day)));
+        }
+    }
+}
@@ -0,0 +1,397 @@
+use std::any::Any;
+
+use crate::answer::Answer;
+use crate::error::SolveError;
+use crate::example::Example;
+use crate::panic::catch_panic;
+use crate::puzzle_input::normalise;
+use crate::timeout::CancellationToken;
+use crate::{day1, day2, day3, day4};
+
+// Puzzle input parsed by a day, shared by the solvers of both parts.
+pub type Model = Box<dyn Any + Send + Sync>;
+
+pub type ParseFn = fn(&str) -> Result<Model, SolveError>;
+// Solvers are given a token that is cancelled when they run out of time.
+pub type SolverFn = fn(&Model, &CancellationToken) -> Result<Answer, SolveError>;
+// Strict grammar of the puzzle input of a day, returning every problem found.
+pub type ValidateFn = fn(&str) -> Vec<SolveError>;
+// Quick check of the shape of the puzzle input of a day, to tell it from the inputs of other days.
+pub type FingerprintFn = fn(&str) -> bool;
+
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    pub title: &'static str,
+    pub input_file: &'static str,
+    pub parse: ParseFn,
+    pub solve: SolverFn,
+    pub validate: ValidateFn,
+    // None for a day whose input shape isn't known yet, like a newly generated one.
+    pub fingerprint: Option<FingerprintFn>,
+    // Examples from the puzzle statement of the day, for both parts.
+    pub day_examples: &'static [Example],
+}
+
+impl Solver {
+    // Parse the input and solve this part in one go.
+    pub fn run(&self, input: &str) -> Result<Answer, SolveError> {
+        (self.solve)(&(self.parse)(input)?, &CancellationToken::new())
+    }
+
+    pub fn examples(&self) -> impl Iterator<Item = &'static Example> + '_ {
+        self.day_examples
+            .iter()
+            .filter(|example| example.part == self.part)
+    }
+}
+
+// Wrap the parse function and part solver of a day module into a type-erased solver, with
+// panics turned into errors and the input normalised before it's parsed or validated. Part
+// solvers marked `cancellable` take the cancellation token as their second argument.
+macro_rules! solver {
+    ($day:literal, $part:literal, $title:literal, $module:ident::$solve:ident) => {
+        solver!(@build $day, $part, $title, $module, |model, _token| $module::$solve(model))
+    };
+    ($day:literal, $part:literal, $title:literal, $module:ident::$solve:ident, cancellable) => {
+        solver!(@build $day, $part, $title, $module, |model, token| $module::$solve(model, token))
+    };
+    (@build $day:literal, $part:literal, $title:literal, $module:ident, |$model:ident, $token:ident| $call:expr) => {
+        Solver {
+            day: $day,
+            part: $part,
+            title: $title,
+            input_file: concat!("day", $day, ".txt"),
+            parse: |input| {
+                catch_panic(|| Ok(Box::new($module::parse(&normalise(input))?) as Model))
+            },
+            solve: |model, $token| match model.downcast_ref() {
+                Some($model) => catch_panic(|| $call),
+                None => Err(SolveError::unsupported(concat!(
+                    "Model was not parsed by day ",
+                    $day
+                ))),
+            },
+            validate: |input| $module::validate(&normalise(input)),
+            fingerprint: $module::FINGERPRINT,
+            day_examples: $module::EXAMPLES,
+        }
+    };
+}
+
+// Registry of every available solver, sorted by day and part.
+pub static SOLVERS: &[Solver] = &[
+    solver!(1, 1, "Trebuchet?!", day1::part1),
+    solver!(1, 2, "Trebuchet?!", day1::part2),
+    solver!(2, 1, "Cube Conundrum", day2::part1),
+    solver!(2, 2, "Cube Conundrum", day2::part2),
+    solver!(3, 1, "Gear Ratios", day3::part1),
+    solver!(3, 2, "Gear Ratios", day3::part2),
+    solver!(4, 1, "Scratchcards", day4::part1),
+    solver!(4, 2, "Scratchcards", day4::part2, cancellable),
+];
+
+pub fn solve(day: u8, part: u8) -> Result<&'static Solver, SolveError> {
+    match SOLVERS
+        .iter()
+        .find(|solver| solver.day == day && solver.part == part)
+    {
+        Some(solver) => Ok(solver),
+        None => Err(SolveError::unsupported(format!(
+            "No solver for day {} and part {}",
+            day, part
+        ))),
+    }
+}
+
+// Solvers matching the optional day and part, in registry order.
+pub fn select(day: Option<u8>, part: Option<u8>) -> impl Iterator<Item = &'static Solver> {
+    SOLVERS.iter().filter(move |solver| {
+        day.is_none_or(|day| solver.day == day) && part.is_none_or(|part| solver.part == part)
+    })
+}
+
+// How a puzzle input given to a day compares to the fingerprints of every day.
+#[derive(Debug, PartialEq)]
+pub enum InputShape {
+    Matches,
+    // Looks like no day, it may still be an unusual input of the day.
+    Unrecognised,
+    LooksLike(u8),
+    // The day has no fingerprint, so there is no telling.
+    Unchecked,
+}
+
+pub fn input_shape(day: u8, input: &str) -> InputShape {
+    input_shape_among(SOLVERS, day, input)
+}
+
+fn input_shape_among(solvers: &[Solver], day: u8, input: &str) -> InputShape {
+    let input = &normalise(input);
+    let mut fingerprints = solvers
+        .chunk_by(|left, right| left.day == right.day)
+        .filter_map(|day_solvers| {
+            day_solvers[0]
+                .fingerprint
+                .map(|fingerprint| (day_solvers[0].day, fingerprint))
+        });
+
+    match fingerprints
+        .clone()
+        .find(|(fingerprint_day, _)| *fingerprint_day == day)
+    {
+        Some((_, fingerprint)) if fingerprint(input) => return InputShape::Matches,
+        Some(_) => {}
+        None => return InputShape::Unchecked,
+    }
+
+    match fingerprints.find(|(other_day, fingerprint)| *other_day != day && fingerprint(input)) {
+        Some((other_day, _)) => InputShape::LooksLike(other_day),
+        None => InputShape::Unrecognised,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::answers::{Answers, Verdict};
+    use crate::error::{ErrorKind, SolveError};
+    use crate::solvers::{
+        input_shape, input_shape_among, select, solve, InputShape, Solver, SOLVERS,
+    };
+    use crate::timeout::CancellationToken;
+
+    #[test]
+    fn test_solvers_are_sorted_and_unique() {
+        for pair in SOLVERS.windows(2) {
+            assert!((pair[0].day, pair[0].part) < (pair[1].day, pair[1].part));
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve(3, 2).unwrap().title, "Gear Ratios");
+        assert_eq!(
+            solve(3, 3).err(),
+            Some(SolveError::unsupported("No solver for day 3 and part 3"))
+        );
+        assert_eq!(
+            solve(26, 1).err().map(|error| error.kind),
+            Some(ErrorKind::Unsupported)
+        );
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(select(None, None).count(), SOLVERS.len());
+        assert_eq!(select(Some(2), None).count(), 2);
+        assert!(select(None, Some(1)).all(|solver| solver.part == 1));
+        assert_eq!(select(Some(4), Some(2)).count(), 1);
+        assert_eq!(select(Some(26), None).count(), 0);
+    }
+
+    #[test]
+    fn test_solve_with_model_of_another_day() {
+        let model = (solve(1, 1).unwrap().parse)("1abc2").unwrap();
+
+        assert_eq!(
+            (solve(2, 1).unwrap().solve)(&model, &CancellationToken::new())
+                .err()
+                .map(|error| error.kind),
+            Some(ErrorKind::Unsupported)
+        );
+    }
+
+    #[test]
+    fn test_solver_panic() {
+        let error = solve(2, 1).unwrap().run("Game 1 3 blue\n").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::Panic);
+        assert!(error
+            .message
+            .starts_with("index out of bounds: the len is 1 but the index is 1 at src/day2.rs:"));
+    }
+
+    #[test]
+    fn test_solvers_with_examples() {
+        for solver in SOLVERS {
+            for example in solver.examples() {
+                assert_eq!(
+                    example.verify(&solver.run(example.input)),
+                    Verdict::Pass,
+                    "Day {}, Part {}",
+                    solver.day,
+                    solver.part
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_examples_and_puzzle_inputs() {
+        let input_folder: PathBuf = [env!("CARGO_MANIFEST_DIR"), "src", "input"]
+            .iter()
+            .collect();
+
+        for solver in SOLVERS {
+            for example in solver.examples() {
+                assert_eq!(
+                    (solver.validate)(example.input),
+                    vec![],
+                    "Day {}",
+                    solver.day
+                );
+            }
+
+            if let Ok(puzzle_input) = fs::read_to_string(input_folder.join(solver.input_file)) {
+                assert_eq!(
+                    (solver.validate)(&puzzle_input),
+                    vec![],
+                    "Day {}",
+                    solver.day
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_input_shape() {
+        let input_folder: PathBuf = [env!("CARGO_MANIFEST_DIR"), "src", "input"]
+            .iter()
+            .collect();
+
+        for solver in SOLVERS {
+            for example in solver.examples() {
+                assert_eq!(
+                    input_shape(solver.day, example.input),
+                    InputShape::Matches,
+                    "Day {}",
+                    solver.day
+                );
+            }
+        }
+
+        for day in 1..=4 {
+            let puzzle_input =
+                fs::read_to_string(input_folder.join(format!("day{}.txt", day))).unwrap();
+
+            for other_day in (1..=4).filter(|other_day| *other_day != day) {
+                assert_eq!(
+                    input_shape(other_day, &puzzle_input),
+                    InputShape::LooksLike(day),
+                    "Input of day {} given to day {}",
+                    day,
+                    other_day
+                );
+            }
+            assert_eq!(input_shape(day, &puzzle_input), InputShape::Matches);
+        }
+
+        assert_eq!(input_shape(1, "1 + 1 = 2\n"), InputShape::Unrecognised);
+        assert_eq!(
+            input_shape(2, "\u{feff}Game 1: 3 blue, 4 red\r\n"),
+            InputShape::Matches
+        );
+    }
+
+    #[test]
+    fn test_input_shape_of_day_without_fingerprint() {
+        let solvers: Vec<Solver> = SOLVERS
+            .iter()
+            .map(|solver| Solver { ..*solver })
+            .chain([Solver {
+                day: 5,
+                fingerprint: None,
+                ..SOLVERS[0]
+            }])
+            .collect();
+        let day1_input = solvers[0].examples().next().unwrap().input;
+
+        assert_eq!(
+            input_shape_among(&solvers, 5, day1_input),
+            InputShape::Unchecked
+        );
+        assert_eq!(
+            input_shape_among(&solvers, 5, "1 + 1 = 2\n"),
+            InputShape::Unchecked
+        );
+        assert_eq!(
+            input_shape_among(&solvers, 1, "1 + 1 = 2\n"),
+            InputShape::Unrecognised
+        );
+        assert_eq!(
+            input_shape_among(&solvers, 1, day1_input),
+            InputShape::Matches
+        );
+    }
+
+    #[test]
+    fn test_solvers_with_unnormalised_puzzle_input() {
+        let input_folder: PathBuf = [env!("CARGO_MANIFEST_DIR"), "src", "input"]
+            .iter()
+            .collect();
+        let answers =
+            Answers::parse(&fs::read_to_string(input_folder.join("answers.txt")).unwrap()).unwrap();
+
+        for solver in SOLVERS {
+            if answers.get(solver.day, solver.part).is_none() {
+                continue;
+            }
+
+            let puzzle_input = fs::read_to_string(input_folder.join(solver.input_file)).unwrap();
+            let variants = [
+                ("BOM", format!("\u{feff}{}", puzzle_input)),
+                ("CRLF", puzzle_input.replace('\n', "\r\n")),
+                ("trailing whitespace", puzzle_input.replace('\n', " \t\n")),
+                ("trailing blank lines", format!("{}\n\n  \n", puzzle_input)),
+                ("no final new line", puzzle_input.trim_end().to_string()),
+            ];
+
+            for (variant, puzzle_input) in variants {
+                assert_eq!(
+                    answers.verify(solver.day, solver.part, &solver.run(&puzzle_input)),
+                    Verdict::Pass,
+                    "Day {}, Part {}, {}",
+                    solver.day,
+                    solver.part,
+                    variant
+                );
+                assert_eq!(
+                    (solver.validate)(&puzzle_input),
+                    vec![],
+                    "Day {}, {}",
+                    solver.day,
+                    variant
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_solvers_with_puzzle_input() {
+        let input_folder: PathBuf = [env!("CARGO_MANIFEST_DIR"), "src", "input"]
+            .iter()
+            .collect();
+        let answers =
+            Answers::parse(&fs::read_to_string(input_folder.join("answers.txt")).unwrap()).unwrap();
+
+        // Solvers of a day without a known answer yet, like a newly generated one, are skipped.
+        for solver in SOLVERS {
+            if answers.get(solver.day, solver.part).is_none() {
+                continue;
+            }
+
+            let puzzle_input = fs::read_to_string(input_folder.join(solver.input_file)).unwrap();
+
+            assert_eq!(
+                answers.verify(solver.day, solver.part, &solver.run(&puzzle_input)),
+                Verdict::Pass,
+                "Day {}, Part {}",
+                solver.day,
+                solver.part
+            );
+        }
+    }
+}
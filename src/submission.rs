@@ -0,0 +1,281 @@
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use regex::Regex;
+
+use crate::answer::Answer;
+use crate::line_file;
+
+// How the website judged a submitted answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited(Option<Duration>),
+    AlreadySolved,
+    Unknown,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Right => write!(f, "That's the right answer"),
+            Outcome::Wrong => write!(f, "That's not the right answer"),
+            Outcome::TooHigh => write!(f, "That's not the right answer, it's too high"),
+            Outcome::TooLow => write!(f, "That's not the right answer, it's too low"),
+            Outcome::RateLimited(Some(wait)) => write!(
+                f,
+                "An answer was given too recently, {}s left to wait",
+                wait.as_secs()
+            ),
+            Outcome::RateLimited(None) => write!(f, "An answer was given too recently"),
+            Outcome::AlreadySolved => write!(f, "This part is already solved"),
+            Outcome::Unknown => write!(f, "The response could not be understood"),
+        }
+    }
+}
+
+impl Outcome {
+    // Classify the HTML page returned after posting an answer.
+    pub fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            return Outcome::Right;
+        }
+
+        if response.contains("That's not the right answer") {
+            if response.contains("your answer is too high") {
+                return Outcome::TooHigh;
+            }
+            if response.contains("your answer is too low") {
+                return Outcome::TooLow;
+            }
+            return Outcome::Wrong;
+        }
+
+        if response.contains("You gave an answer too recently") {
+            let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait")
+                .unwrap()
+                .captures(response)
+                .map(|captures| {
+                    let minutes = match captures.get(1) {
+                        Some(minutes) => minutes.as_str().parse::<u64>().unwrap_or(0),
+                        None => 0,
+                    };
+                    let seconds = captures[2].parse::<u64>().unwrap_or(0);
+                    Duration::from_secs(minutes * 60 + seconds)
+                });
+            return Outcome::RateLimited(wait);
+        }
+
+        if response.contains("Did you already complete it?") {
+            return Outcome::AlreadySolved;
+        }
+
+        Outcome::Unknown
+    }
+
+    // Keyword stored in the submission log, for the outcomes that judge the answer itself.
+    fn keyword(&self) -> Option<&'static str> {
+        match self {
+            Outcome::Right => Some("right"),
+            Outcome::Wrong => Some("wrong"),
+            Outcome::TooHigh => Some("too-high"),
+            Outcome::TooLow => Some("too-low"),
+            _ => None,
+        }
+    }
+
+    fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "right" => Some(Outcome::Right),
+            "wrong" => Some(Outcome::Wrong),
+            "too-high" => Some(Outcome::TooHigh),
+            "too-low" => Some(Outcome::TooLow),
+            _ => None,
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+// Judged submissions, stored with one "<day> <part> <outcome> <answer>" entry per line.
+#[derive(Debug, Default, PartialEq)]
+pub struct SubmissionLog {
+    entries: Vec<(u8, u8, Outcome, Answer)>,
+}
+
+impl SubmissionLog {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut entries = Vec::new();
+
+        line_file::parse_lines(content, |line| {
+            let mut fields = line.splitn(4, char::is_whitespace);
+
+            let entry = match (fields.next(), fields.next(), fields.next(), fields.next()) {
+                (Some(day), Some(part), Some(outcome), Some(answer)) => match (
+                    day.parse::<u8>(),
+                    part.parse::<u8>(),
+                    Outcome::from_keyword(outcome),
+                ) {
+                    (Ok(day), Ok(part), Some(outcome)) => {
+                        let Ok(answer) = answer.parse::<Answer>();
+                        Some((day, part, outcome, answer))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            match entry {
+                Some(entry) => {
+                    entries.push(entry);
+                    Ok(())
+                }
+                None => Err(format!(
+                    "expected '<day> <part> <outcome> <answer>', found {:?}",
+                    line
+                )),
+            }
+        })?;
+
+        Ok(Self { entries })
+    }
+
+    // A missing log file is an empty log.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        Self::parse(&line_file::read(path)?)
+    }
+
+    // Outcome of an earlier submission of `answer`, if it was judged.
+    pub fn previous(&self, day: u8, part: u8, answer: &Answer) -> Option<&Outcome> {
+        self.entries
+            .iter()
+            .find(|entry| entry.0 == day && entry.1 == part && &entry.3 == answer)
+            .map(|entry| &entry.2)
+    }
+
+    // Add a judged submission to the log and to the log file. Other outcomes aren't recorded.
+    pub fn record(
+        &mut self,
+        path: &Path,
+        day: u8,
+        part: u8,
+        outcome: &Outcome,
+        answer: &Answer,
+    ) -> io::Result<()> {
+        let keyword = match outcome.keyword() {
+            Some(keyword) => keyword,
+            None => return Ok(()),
+        };
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{} {} {} {}", day, part, keyword, answer)?;
+
+        self.entries
+            .push((day, part, outcome.clone(), answer.clone()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::Duration;
+
+    use crate::answer::Answer;
+    use crate::submission::{Outcome, SubmissionLog};
+    use crate::temp_dir::TempDir;
+
+    #[test]
+    fn test_outcome_from_response() {
+        assert_eq!(
+            Outcome::from_response("<article><p>That's the right answer!  You are one gold star closer to restoring snow operations.</p></article>"),
+            Outcome::Right
+        );
+        assert_eq!(
+            Outcome::from_response("<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.</p></article>"),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::from_response(
+                "<article><p>That's not the right answer; your answer is too low.</p></article>"
+            ),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::from_response("<article><p>That's not the right answer.  If you're stuck, there are some general tips on the about page.</p></article>"),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::from_response("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 12s left to wait.</p></article>"),
+            Outcome::RateLimited(Some(Duration::from_secs(252)))
+        );
+        assert_eq!(
+            Outcome::from_response("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 31s left to wait.</p></article>"),
+            Outcome::RateLimited(Some(Duration::from_secs(31)))
+        );
+        assert_eq!(
+            Outcome::from_response("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            Outcome::AlreadySolved
+        );
+        assert_eq!(Outcome::from_response("<html></html>"), Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_parse() {
+        let log =
+            SubmissionLog::parse("# Submissions\n3 1 too-high 539591\n3 1 right 539590\n").unwrap();
+
+        assert_eq!(
+            log.previous(3, 1, &Answer::from(539591)),
+            Some(&Outcome::TooHigh)
+        );
+        assert_eq!(
+            log.previous(3, 1, &Answer::from(539590)),
+            Some(&Outcome::Right)
+        );
+        assert_eq!(log.previous(3, 2, &Answer::from(539590)), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_line() {
+        assert_eq!(
+            SubmissionLog::parse("3 1 539590"),
+            Err(
+                "Line 1: expected '<day> <part> <outcome> <answer>', found \"3 1 539590\""
+                    .to_string()
+            )
+        );
+        assert!(SubmissionLog::parse("3 1 maybe 539590").is_err());
+    }
+
+    #[test]
+    fn test_record() {
+        let temp_dir = TempDir::new("submissions");
+        let path = temp_dir.join("submissions.txt");
+
+        let mut log = SubmissionLog::load(&path).unwrap();
+        log.record(&path, 1, 2, &Outcome::TooLow, &Answer::from(55290))
+            .unwrap();
+        log.record(
+            &path,
+            1,
+            2,
+            &Outcome::RateLimited(None),
+            &Answer::from(55291),
+        )
+        .unwrap();
+
+        assert!(log.previous(1, 2, &Answer::from(55290)).unwrap().is_wrong());
+        assert_eq!(log.previous(1, 2, &Answer::from(55291)), None);
+        assert_eq!(SubmissionLog::load(&path).unwrap(), log);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 too-low 55290\n");
+    }
+}
@@ -0,0 +1,151 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::bench::format_duration;
+use crate::error::SolveError;
+
+// Shared flag set when a solver ran out of time. Long-running solvers can check it to stop early.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    // Error out of a solver once cancelled, meant to be used with `?` inside its loops.
+    pub fn check(&self) -> Result<(), SolveError> {
+        match self.is_cancelled() {
+            true => Err(SolveError::timeout("Solver was cancelled")),
+            false => Ok(()),
+        }
+    }
+}
+
+// Run `function` on a worker thread and stop waiting for it after `timeout`. The token given to
+// `function` is then cancelled; a solver ignoring it keeps running in the background.
+pub fn run_with_timeout<T, F>(timeout: Duration, function: F) -> Result<T, SolveError>
+where
+    T: Send + 'static,
+    F: FnOnce(CancellationToken) -> T + Send + 'static,
+{
+    let token = CancellationToken::new();
+    let worker_token = token.clone();
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let _ = sender.send(function(worker_token));
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => Ok(result),
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            Err(SolveError::timeout(format!(
+                "No answer after {}",
+                format_duration(timeout)
+            )))
+        }
+        Err(RecvTimeoutError::Disconnected) => {
+            Err(SolveError::panic("Worker thread stopped without a result"))
+        }
+    }
+}
+
+// Parse a duration such as "500ms", "2s", "1.5m" or "250us". A number without unit is in seconds.
+pub fn parse_duration(text: &str) -> Result<Duration, String> {
+    let text = text.trim();
+    let unit_index = text
+        .find(|character: char| !(character.is_ascii_digit() || character == '.'))
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(unit_index);
+
+    let seconds_per_unit = match unit {
+        "ns" => 0.000_000_001,
+        "us" | "μs" => 0.000_001,
+        "ms" => 0.001,
+        "" | "s" => 1.0,
+        "m" => 60.0,
+        _ => {
+            return Err(format!(
+                "Unknown unit {:?}, expected ns, us, ms, s or m",
+                unit
+            ))
+        }
+    };
+
+    match number.parse::<f64>() {
+        Ok(number) => Duration::try_from_secs_f64(number * seconds_per_unit)
+            .map_err(|error| error.to_string()),
+        Err(_) => Err(format!("Invalid duration {:?}", text)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::error::{ErrorKind, SolveError};
+    use crate::timeout::{parse_duration, run_with_timeout, CancellationToken};
+
+    #[test]
+    fn test_cancellation_token() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+
+        assert_eq!(clone.check(), Ok(()));
+        token.cancel();
+        assert!(clone.is_cancelled());
+        assert_eq!(
+            clone.check().map_err(|error| error.kind),
+            Err(ErrorKind::Timeout)
+        );
+    }
+
+    #[test]
+    fn test_run_with_timeout() {
+        assert_eq!(run_with_timeout(Duration::from_secs(10), |_| 142), Ok(142));
+    }
+
+    #[test]
+    fn test_run_with_timeout_exceeded() {
+        let (sender, receiver) = mpsc::channel();
+
+        let result = run_with_timeout(Duration::from_millis(20), move |token| {
+            while !token.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            sender.send(()).unwrap();
+        });
+
+        assert_eq!(result, Err(SolveError::timeout("No answer after 20.00ms")));
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(10)), Ok(()));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("2s"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration("3"), Ok(Duration::from_secs(3)));
+        assert_eq!(parse_duration("1.5m"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("250us"), Ok(Duration::from_micros(250)));
+        assert!(parse_duration("2h").is_err());
+        assert!(parse_duration("ms").is_err());
+        assert!(parse_duration("1.2.3s").is_err());
+    }
+}
@@ -0,0 +1,242 @@
+use crate::error::SolveError;
+
+// Reads a line of puzzle input token by token, for the strict grammars of --validate. Errors
+// point at the current position, with the column counted in characters.
+pub struct LineScanner<'a> {
+    line: &'a str,
+    line_number: usize,
+    offset: usize,
+}
+
+impl<'a> LineScanner<'a> {
+    pub fn new(line: &'a str, line_number: usize) -> Self {
+        Self {
+            line,
+            line_number,
+            offset: 0,
+        }
+    }
+
+    pub fn line(&self) -> &'a str {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.line[..self.offset].chars().count() + 1
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.line[self.offset..].chars().next()
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.offset == self.line.len()
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> SolveError {
+        self.error_at(self.column(), message)
+    }
+
+    pub fn error_at(&self, column: usize, message: impl Into<String>) -> SolveError {
+        SolveError::invalid_input(message).at(self.line_number, column)
+    }
+
+    // Description of what comes next, for error messages.
+    pub fn found(&self) -> String {
+        match self.peek() {
+            Some(character) => format!("{:?}", character),
+            None => "the end of the line".to_string(),
+        }
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<(), SolveError> {
+        match self.line[self.offset..].starts_with(literal) {
+            true => {
+                self.offset += literal.len();
+                Ok(())
+            }
+            false => Err(self.error(format!("Expected {:?}, found {}", literal, self.found()))),
+        }
+    }
+
+    pub fn next_char(&mut self) -> Option<char> {
+        let character = self.peek()?;
+        self.offset += character.len_utf8();
+        Some(character)
+    }
+
+    // Skip spaces, returning how many there were.
+    pub fn spaces(&mut self) -> usize {
+        let count = self.line[self.offset..]
+            .bytes()
+            .take_while(|byte| *byte == b' ')
+            .count();
+        self.offset += count;
+        count
+    }
+
+    pub fn number(&mut self) -> Result<u64, SolveError> {
+        let digits = self.line[self.offset..]
+            .bytes()
+            .take_while(u8::is_ascii_digit)
+            .count();
+
+        if digits == 0 {
+            return Err(self.error(format!("Expected a number, found {}", self.found())));
+        }
+
+        match self.line[self.offset..self.offset + digits].parse::<u64>() {
+            Ok(number) => {
+                self.offset += digits;
+                Ok(number)
+            }
+            Err(_) => Err(self.error("Number is too large")),
+        }
+    }
+
+    // Lowercase ASCII word, possibly empty.
+    pub fn word(&mut self) -> &'a str {
+        let length = self.line[self.offset..]
+            .bytes()
+            .take_while(u8::is_ascii_lowercase)
+            .count();
+        let word = &self.line[self.offset..self.offset + length];
+        self.offset += length;
+        word
+    }
+
+    pub fn end(&self) -> Result<(), SolveError> {
+        match self.is_at_end() {
+            true => Ok(()),
+            false => Err(self.error(format!("Unexpected {}", self.found()))),
+        }
+    }
+}
+
+// Check every line of `input` with `validate_line`, collecting the first problem of each line.
+// An empty input and empty lines are always problems.
+pub fn validate_lines<F>(input: &str, mut validate_line: F) -> Vec<SolveError>
+where
+    F: FnMut(&mut LineScanner) -> Result<(), SolveError>,
+{
+    if input.is_empty() {
+        return vec![SolveError::invalid_input("Input is empty").at(1, 1)];
+    }
+
+    let mut errors = Vec::new();
+
+    for (line_index, line) in input.split_terminator('\n').enumerate() {
+        let mut scanner = LineScanner::new(line, line_index + 1);
+
+        let result = match line.is_empty() {
+            true => Err(scanner.error("Empty line")),
+            false => validate_line(&mut scanner),
+        };
+        if let Err(error) = result {
+            errors.push(error);
+        }
+    }
+
+    errors
+}
+
+// Whether the input has a line that isn't blank and every such line has the given shape,
+// ignoring line terminators and trailing whitespace. Fingerprints of the days use it to tell
+// their inputs apart without checking the whole grammar.
+pub fn all_lines_match<F>(input: &str, shape: F) -> bool
+where
+    F: FnMut(&str) -> bool,
+{
+    let mut lines = input
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+        .peekable();
+
+    lines.peek().is_some() && lines.all(shape)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::SolveError;
+    use crate::validation::{all_lines_match, validate_lines, LineScanner};
+
+    #[test]
+    fn test_line_scanner() {
+        let mut scanner = LineScanner::new("Card  12: 41 é", 3);
+
+        assert_eq!(scanner.literal("Card"), Ok(()));
+        assert_eq!(scanner.spaces(), 2);
+        assert_eq!(scanner.number(), Ok(12));
+        assert_eq!(
+            scanner.literal("|"),
+            Err(SolveError::invalid_input("Expected \"|\", found ':'").at(3, 9))
+        );
+        assert_eq!(scanner.literal(": "), Ok(()));
+        assert_eq!(scanner.number(), Ok(41));
+        assert_eq!(scanner.spaces(), 1);
+        assert_eq!(
+            scanner.number(),
+            Err(SolveError::invalid_input("Expected a number, found 'é'").at(3, 14))
+        );
+        assert_eq!(scanner.word(), "");
+        assert_eq!(scanner.next_char(), Some('é'));
+        assert!(scanner.is_at_end());
+        assert_eq!(
+            scanner.literal(":"),
+            Err(SolveError::invalid_input("Expected \":\", found the end of the line").at(3, 15))
+        );
+        assert_eq!(scanner.end(), Ok(()));
+    }
+
+    #[test]
+    fn test_line_scanner_unexpected_character() {
+        let scanner = LineScanner::new("é", 1);
+
+        assert_eq!(
+            scanner.end(),
+            Err(SolveError::invalid_input("Unexpected 'é'").at(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_line_scanner_number_too_large() {
+        let mut scanner = LineScanner::new("99999999999999999999", 1);
+
+        assert_eq!(
+            scanner.number(),
+            Err(SolveError::invalid_input("Number is too large").at(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_validate_lines() {
+        let validate_word = |scanner: &mut LineScanner| {
+            scanner.word();
+            scanner.end()
+        };
+
+        assert_eq!(validate_lines("abc\ndef\n", validate_word), vec![]);
+        assert_eq!(
+            validate_lines("abc\n\nd1f", validate_word),
+            vec![
+                SolveError::invalid_input("Empty line").at(2, 1),
+                SolveError::invalid_input("Unexpected '1'").at(3, 2),
+            ]
+        );
+        assert_eq!(
+            validate_lines("", validate_word),
+            vec![SolveError::invalid_input("Input is empty").at(1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_all_lines_match() {
+        let is_word = |line: &str| line.bytes().all(|byte| byte.is_ascii_lowercase());
+
+        assert!(all_lines_match("abc\r\ndef  \n\n", is_word));
+        assert!(!all_lines_match("abc\nd1f\n", is_word));
+        assert!(!all_lines_match("", is_word));
+        assert!(!all_lines_match(" \n\n", is_word));
+    }
+}
@@ -0,0 +1,169 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::answer::Answer;
+use crate::error::{ErrorKind, SolveError};
+
+// Polls the modification time of files. Every existing file counts as changed on the first poll.
+pub struct FileWatcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+impl FileWatcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        Self {
+            files: paths.into_iter().map(|path| (path, None)).collect(),
+        }
+    }
+
+    // Files created or modified since the previous poll.
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+
+        for (path, last_modified) in self.files.iter_mut() {
+            let modified = modified(path);
+
+            if modified.is_some() && modified != *last_modified {
+                changed.push(path.clone());
+            }
+            *last_modified = modified;
+        }
+
+        changed
+    }
+}
+
+// Solution of a run as printed, e.g. "142", "TIMEOUT" or "ERROR: ...".
+pub fn solution(result: &Result<Answer, SolveError>) -> String {
+    match result {
+        Ok(answer) => answer.to_string(),
+        Err(error) if error.kind == ErrorKind::Timeout => "TIMEOUT".to_string(),
+        Err(error) => format!("ERROR: {}", error),
+    }
+}
+
+// Solution of a new run compared to the previous one, e.g. "142 -> 143" or "142 (unchanged)".
+pub fn solution_change(
+    previous: &Result<Answer, SolveError>,
+    current: &Result<Answer, SolveError>,
+) -> String {
+    let unchanged = match (previous, current) {
+        (Ok(previous), Ok(current)) => previous == current,
+        (Err(previous), Err(current)) => previous == current,
+        _ => false,
+    };
+
+    match unchanged {
+        true => format!("{} (unchanged)", solution(current)),
+        false => format!("{} -> {}", solution(previous), solution(current)),
+    }
+}
+
+// Signed difference between two timings, e.g. "+25μs", "-3μs" or "±0μs" under a microsecond.
+pub fn time_delta(previous: Duration, current: Duration) -> String {
+    match current.checked_sub(previous) {
+        Some(slower) if slower.as_micros() > 0 => format!("+{}μs", slower.as_micros()),
+        None if (previous - current).as_micros() > 0 => {
+            format!("-{}μs", (previous - current).as_micros())
+        }
+        _ => "±0μs".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+    use std::time::{Duration, SystemTime};
+
+    use crate::answer::Answer;
+    use crate::error::SolveError;
+    use crate::temp_dir::TempDir;
+    use crate::watch::{solution, solution_change, time_delta, FileWatcher};
+
+    #[test]
+    fn test_file_watcher() {
+        let temp_dir = TempDir::new("watch");
+        let path = temp_dir.join("day1.txt");
+        let mut watcher = FileWatcher::new(vec![path.clone()]);
+
+        assert!(watcher.changed().is_empty());
+
+        fs::write(&path, "1abc2\n").unwrap();
+        assert_eq!(watcher.changed(), vec![path.clone()]);
+        assert!(watcher.changed().is_empty());
+
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(10))
+            .unwrap();
+        assert_eq!(watcher.changed(), vec![path.clone()]);
+
+        fs::remove_file(&path).unwrap();
+        assert!(watcher.changed().is_empty());
+    }
+
+    #[test]
+    fn test_solution_change() {
+        assert_eq!(
+            solution_change(&Ok(Answer::from(142)), &Ok(Answer::from(142_u64))),
+            "142 (unchanged)"
+        );
+        assert_eq!(
+            solution_change(&Ok(Answer::from(142)), &Ok(Answer::from(143))),
+            "142 -> 143"
+        );
+        assert_eq!(
+            solution_change(
+                &Err(SolveError::parse("Couldn't parse \"x\"")),
+                &Ok(Answer::from(143))
+            ),
+            "ERROR: Parse error: Couldn't parse \"x\" -> 143"
+        );
+        assert_eq!(
+            solution_change(
+                &Err(SolveError::timeout("Timed out")),
+                &Err(SolveError::timeout("Timed out"))
+            ),
+            "TIMEOUT (unchanged)"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        assert_eq!(solution(&Ok(Answer::from(142))), "142");
+        assert_eq!(solution(&Err(SolveError::timeout("Timed out"))), "TIMEOUT");
+        assert_eq!(
+            solution(&Err(SolveError::parse("Couldn't parse \"x\""))),
+            "ERROR: Parse error: Couldn't parse \"x\""
+        );
+    }
+
+    #[test]
+    fn test_time_delta() {
+        assert_eq!(
+            time_delta(Duration::from_micros(100), Duration::from_micros(125)),
+            "+25μs"
+        );
+        assert_eq!(
+            time_delta(Duration::from_micros(100), Duration::from_micros(97)),
+            "-3μs"
+        );
+        assert_eq!(
+            time_delta(Duration::from_nanos(100_500), Duration::from_nanos(100_200)),
+            "±0μs"
+        );
+        assert_eq!(
+            time_delta(Duration::from_nanos(100_200), Duration::from_nanos(100_500)),
+            "±0μs"
+        );
+    }
+}